@@ -6,12 +6,36 @@
 use crate::*;
 
 // chunk
+pub type btrfs_balance_item = BalanceItem;
 pub type btrfs_chunk = Chunk;
+pub type btrfs_disk_balance_args = BalanceArgs;
 pub type btrfs_stripe = Stripe;
 
+pub const BTRFS_BALANCE_DATA: u64 = BalanceFlags::DATA.bits();
+pub const BTRFS_BALANCE_SYSTEM: u64 = BalanceFlags::SYSTEM.bits();
+pub const BTRFS_BALANCE_METADATA: u64 = BalanceFlags::METADATA.bits();
+pub const BTRFS_BALANCE_FORCE: u64 = BalanceFlags::FORCE.bits();
+pub const BTRFS_BALANCE_RESUME: u64 = BalanceFlags::RESUME.bits();
+
+pub const BTRFS_BALANCE_ARGS_PROFILES: u64 = BalanceArgsFlags::PROFILES.bits();
+pub const BTRFS_BALANCE_ARGS_USAGE: u64 = BalanceArgsFlags::USAGE.bits();
+pub const BTRFS_BALANCE_ARGS_DEVID: u64 = BalanceArgsFlags::DEVID.bits();
+pub const BTRFS_BALANCE_ARGS_DRANGE: u64 = BalanceArgsFlags::DRANGE.bits();
+pub const BTRFS_BALANCE_ARGS_VRANGE: u64 = BalanceArgsFlags::VRANGE.bits();
+pub const BTRFS_BALANCE_ARGS_LIMIT: u64 = BalanceArgsFlags::LIMIT.bits();
+pub const BTRFS_BALANCE_ARGS_LIMIT_RANGE: u64 = BalanceArgsFlags::LIMIT_RANGE.bits();
+pub const BTRFS_BALANCE_ARGS_STRIPES_RANGE: u64 = BalanceArgsFlags::STRIPES_RANGE.bits();
+pub const BTRFS_BALANCE_ARGS_CONVERT: u64 = BalanceArgsFlags::CONVERT.bits();
+pub const BTRFS_BALANCE_ARGS_SOFT: u64 = BalanceArgsFlags::SOFT.bits();
+pub const BTRFS_BALANCE_ARGS_USAGE_RANGE: u64 = BalanceArgsFlags::USAGE_RANGE.bits();
+
 // core
 pub type btrfs_dev_item = DevItem;
+pub type btrfs_dir_item = DirItem;
+pub type btrfs_file_extent_item = FileExtentItem;
 pub type btrfs_inode_item = InodeItem;
+pub type btrfs_inode_ref = InodeRef;
+pub type btrfs_inode_extref = InodeExtref;
 pub type btrfs_disk_key = Key;
 pub type btrfs_header = Header;
 pub type btrfs_root_backup = RootBackup;
@@ -25,6 +49,25 @@ pub const BTRFS_CSUM_TYPE_XXHASH: u16 = ChecksumType::XXHASH64 as u16;
 pub const BTRFS_CSUM_TYPE_SHA256: u16 = ChecksumType::SHA256 as u16;
 pub const BTRFS_CSUM_TYPE_BLAKE2: u16 = ChecksumType::BLAKE2b as u16;
 
+pub const BTRFS_COMPRESS_NONE: u8 = CompressionType::None as u8;
+pub const BTRFS_COMPRESS_ZLIB: u8 = CompressionType::Zlib as u8;
+pub const BTRFS_COMPRESS_LZO: u8 = CompressionType::Lzo as u8;
+pub const BTRFS_COMPRESS_ZSTD: u8 = CompressionType::Zstd as u8;
+
+pub const BTRFS_FILE_EXTENT_INLINE: u8 = FileExtentType::Inline as u8;
+pub const BTRFS_FILE_EXTENT_REG: u8 = FileExtentType::Regular as u8;
+pub const BTRFS_FILE_EXTENT_PREALLOC: u8 = FileExtentType::Prealloc as u8;
+
+pub const BTRFS_FT_UNKNOWN: u8 = DirEntryType::Unknown as u8;
+pub const BTRFS_FT_REG_FILE: u8 = DirEntryType::RegularFile as u8;
+pub const BTRFS_FT_DIR: u8 = DirEntryType::Directory as u8;
+pub const BTRFS_FT_CHRDEV: u8 = DirEntryType::CharDevice as u8;
+pub const BTRFS_FT_BLKDEV: u8 = DirEntryType::BlockDevice as u8;
+pub const BTRFS_FT_FIFO: u8 = DirEntryType::Fifo as u8;
+pub const BTRFS_FT_SOCK: u8 = DirEntryType::Socket as u8;
+pub const BTRFS_FT_SYMLINK: u8 = DirEntryType::Symlink as u8;
+pub const BTRFS_FT_XATTR: u8 = DirEntryType::Xattr as u8;
+
 pub const BTRFS_INODE_NODATASUM: u64 = InodeFlags::NO_DATA_SUM.bits();
 pub const BTRFS_INODE_NODATACOW: u64 = InodeFlags::NO_DATA_COW.bits();
 pub const BTRFS_INODE_READONLY: u64 = InodeFlags::READ_ONLY.bits();
@@ -38,14 +81,145 @@ pub const BTRFS_INODE_NOATIME: u64 = InodeFlags::NO_ATIME.bits();
 pub const BTRFS_INODE_DIRSYNC: u64 = InodeFlags::DIR_SYNC.bits();
 pub const BTRFS_INODE_COMPRESS: u64 = InodeFlags::COMPRESS.bits();
 
+pub const BTRFS_INODE_ITEM_KEY: u8 = KeyType::InodeItem as u8;
+pub const BTRFS_INODE_REF_KEY: u8 = KeyType::InodeRef as u8;
+pub const BTRFS_INODE_EXTREF_KEY: u8 = KeyType::InodeExtref as u8;
+pub const BTRFS_XATTR_ITEM_KEY: u8 = KeyType::XattrItem as u8;
+pub const BTRFS_VERITY_DESC_ITEM_KEY: u8 = KeyType::VerityDescItem as u8;
+pub const BTRFS_VERITY_MERKLE_ITEM_KEY: u8 = KeyType::VerityMerkleItem as u8;
+pub const BTRFS_ORPHAN_ITEM_KEY: u8 = KeyType::OrphanItem as u8;
+pub const BTRFS_DIR_LOG_ITEM_KEY: u8 = KeyType::DirLogItem as u8;
+pub const BTRFS_DIR_LOG_INDEX_KEY: u8 = KeyType::DirLogIndex as u8;
+pub const BTRFS_DIR_ITEM_KEY: u8 = KeyType::DirItem as u8;
+pub const BTRFS_DIR_INDEX_KEY: u8 = KeyType::DirIndex as u8;
+pub const BTRFS_EXTENT_DATA_KEY: u8 = KeyType::ExtentData as u8;
+pub const BTRFS_EXTENT_CSUM_KEY: u8 = KeyType::ExtentCsum as u8;
+pub const BTRFS_ROOT_ITEM_KEY: u8 = KeyType::RootItem as u8;
+pub const BTRFS_ROOT_BACKREF_KEY: u8 = KeyType::RootBackref as u8;
+pub const BTRFS_ROOT_REF_KEY: u8 = KeyType::RootRef as u8;
+pub const BTRFS_EXTENT_ITEM_KEY: u8 = KeyType::ExtentItem as u8;
+pub const BTRFS_METADATA_ITEM_KEY: u8 = KeyType::MetadataItem as u8;
+pub const BTRFS_EXTENT_OWNER_REF_KEY: u8 = KeyType::ExtentOwnerRef as u8;
+pub const BTRFS_BLOCK_GROUP_ITEM_KEY: u8 = KeyType::BlockGroupItem as u8;
+pub const BTRFS_FREE_SPACE_INFO_KEY: u8 = KeyType::FreeSpaceInfo as u8;
+pub const BTRFS_FREE_SPACE_EXTENT_KEY: u8 = KeyType::FreeSpaceExtent as u8;
+pub const BTRFS_FREE_SPACE_BITMAP_KEY: u8 = KeyType::FreeSpaceBitmap as u8;
+pub const BTRFS_DEV_EXTENT_KEY: u8 = KeyType::DevExtent as u8;
+pub const BTRFS_DEV_ITEM_KEY: u8 = KeyType::DevItem as u8;
+pub const BTRFS_CHUNK_ITEM_KEY: u8 = KeyType::ChunkItem as u8;
+pub const BTRFS_RAID_STRIPE_KEY: u8 = KeyType::RaidStripe as u8;
+pub const BTRFS_QGROUP_STATUS_KEY: u8 = KeyType::QgroupStatus as u8;
+pub const BTRFS_QGROUP_INFO_KEY: u8 = KeyType::QgroupInfo as u8;
+pub const BTRFS_QGROUP_LIMIT_KEY: u8 = KeyType::QgroupLimit as u8;
+pub const BTRFS_QGROUP_RELATION_KEY: u8 = KeyType::QgroupRelation as u8;
+pub const BTRFS_TEMPORARY_ITEM_KEY: u8 = KeyType::TemporaryItem as u8;
+pub const BTRFS_BALANCE_ITEM_KEY: u8 = KeyType::TemporaryItem as u8;
+pub const BTRFS_PERSISTENT_ITEM_KEY: u8 = KeyType::PersistentItem as u8;
+pub const BTRFS_DEV_STATS_KEY: u8 = KeyType::PersistentItem as u8;
+pub const BTRFS_DEV_REPLACE_KEY: u8 = KeyType::DevReplace as u8;
+pub const BTRFS_UUID_KEY_SUBVOL: u8 = KeyType::UuidKeySubvol as u8;
+pub const BTRFS_UUID_KEY_RECEIVED_SUBVOL: u8 = KeyType::UuidKeyReceivedSubvol as u8;
+pub const BTRFS_STRING_ITEM_KEY: u8 = KeyType::StringItem as u8;
+
+pub const BTRFS_ROOT_TREE_OBJECTID: u64 = ObjectId::ROOT_TREE.0;
+pub const BTRFS_EXTENT_TREE_OBJECTID: u64 = ObjectId::EXTENT_TREE.0;
+pub const BTRFS_CHUNK_TREE_OBJECTID: u64 = ObjectId::CHUNK_TREE.0;
+pub const BTRFS_DEV_TREE_OBJECTID: u64 = ObjectId::DEV_TREE.0;
+pub const BTRFS_FS_TREE_OBJECTID: u64 = ObjectId::FS_TREE.0;
+pub const BTRFS_ROOT_TREE_DIR_OBJECTID: u64 = ObjectId::ROOT_TREE_DIR.0;
+pub const BTRFS_CSUM_TREE_OBJECTID: u64 = ObjectId::CSUM_TREE.0;
+pub const BTRFS_QUOTA_TREE_OBJECTID: u64 = ObjectId::QUOTA_TREE.0;
+pub const BTRFS_UUID_TREE_OBJECTID: u64 = ObjectId::UUID_TREE.0;
+pub const BTRFS_FREE_SPACE_TREE_OBJECTID: u64 = ObjectId::FREE_SPACE_TREE.0;
+pub const BTRFS_BLOCK_GROUP_TREE_OBJECTID: u64 = ObjectId::BLOCK_GROUP_TREE.0;
+pub const BTRFS_RAID_STRIPE_TREE_OBJECTID: u64 = ObjectId::RAID_STRIPE_TREE.0;
+pub const BTRFS_DEV_STATS_OBJECTID: u64 = ObjectId::DEV_STATS.0;
+pub const BTRFS_DEV_ITEMS_OBJECTID: u64 = ObjectId::DEV_ITEMS.0;
+pub const BTRFS_BALANCE_OBJECTID: u64 = ObjectId::BALANCE.0;
+pub const BTRFS_ORPHAN_OBJECTID: u64 = ObjectId::ORPHAN.0;
+pub const BTRFS_TREE_LOG_OBJECTID: u64 = ObjectId::TREE_LOG.0;
+pub const BTRFS_TREE_LOG_FIXUP_OBJECTID: u64 = ObjectId::TREE_LOG_FIXUP.0;
+pub const BTRFS_TREE_RELOC_OBJECTID: u64 = ObjectId::TREE_RELOC.0;
+pub const BTRFS_DATA_RELOC_TREE_OBJECTID: u64 = ObjectId::DATA_RELOC_TREE.0;
+pub const BTRFS_EXTENT_CSUM_OBJECTID: u64 = ObjectId::EXTENT_CSUM.0;
+pub const BTRFS_FREE_SPACE_OBJECTID: u64 = ObjectId::FREE_SPACE.0;
+pub const BTRFS_FREE_INO_OBJECTID: u64 = ObjectId::FREE_INO.0;
+pub const BTRFS_MULTIPLE_OBJECTIDS: u64 = ObjectId::MULTIPLE.0;
+pub const BTRFS_FIRST_FREE_OBJECTID: u64 = ObjectId::FIRST_FREE.0;
+pub const BTRFS_LAST_FREE_OBJECTID: u64 = ObjectId::LAST_FREE.0;
+pub const BTRFS_FIRST_CHUNK_TREE_OBJECTID: u64 = ObjectId::FIRST_CHUNK_TREE.0;
+pub const BTRFS_BTREE_INODE_OBJECTID: u64 = ObjectId::BTREE_INODE.0;
+pub const BTRFS_EMPTY_SUBVOL_DIR_OBJECTID: u64 = ObjectId::EMPTY_SUBVOL_DIR.0;
+
+pub const BTRFS_HEADER_FLAG_WRITTEN: u64 = HeaderFlags::WRITTEN.bits();
+pub const BTRFS_HEADER_FLAG_RELOC: u64 = HeaderFlags::RELOC.bits();
+pub const BTRFS_OLD_BACKREF_REV: u8 = BackrefRevision::Old as u8;
+pub const BTRFS_MIXED_BACKREF_REV: u8 = BackrefRevision::Mixed as u8;
+
+pub const BTRFS_ROOT_SUBVOL_RDONLY: u64 = RootFlags::SUBVOL_RDONLY.bits();
+pub const BTRFS_ROOT_SUBVOL_DEAD: u64 = RootFlags::SUBVOL_DEAD.bits();
+
+pub const BTRFS_SUPER_FLAG_WRITTEN: u64 = SuperBlockFlags::WRITTEN.bits();
+pub const BTRFS_SUPER_FLAG_RELOC: u64 = SuperBlockFlags::RELOC.bits();
+pub const BTRFS_SUPER_FLAG_ERROR: u64 = SuperBlockFlags::ERROR.bits();
+pub const BTRFS_SUPER_FLAG_SEEDING: u64 = SuperBlockFlags::SEEDING.bits();
+pub const BTRFS_SUPER_FLAG_METADUMP: u64 = SuperBlockFlags::METADUMP.bits();
+pub const BTRFS_SUPER_FLAG_METADUMP_V2: u64 = SuperBlockFlags::METADUMP_V2.bits();
+pub const BTRFS_SUPER_FLAG_CHANGING_FSID: u64 = SuperBlockFlags::CHANGING_FSID.bits();
+pub const BTRFS_SUPER_FLAG_CHANGING_FSID_V2: u64 = SuperBlockFlags::CHANGING_FSID_V2.bits();
+
+pub const BTRFS_FEATURE_COMPAT_RO_FREE_SPACE_TREE: u64 = CompatRoFlags::FREE_SPACE_TREE.bits();
+pub const BTRFS_FEATURE_COMPAT_RO_FREE_SPACE_TREE_VALID: u64 =
+    CompatRoFlags::FREE_SPACE_TREE_VALID.bits();
+pub const BTRFS_FEATURE_COMPAT_RO_VERITY: u64 = CompatRoFlags::VERITY.bits();
+pub const BTRFS_FEATURE_COMPAT_RO_BLOCK_GROUP_TREE: u64 = CompatRoFlags::BLOCK_GROUP_TREE.bits();
+
+pub const BTRFS_FEATURE_INCOMPAT_MIXED_BACKREF: u64 = IncompatFlags::MIXED_BACKREF.bits();
+pub const BTRFS_FEATURE_INCOMPAT_DEFAULT_SUBVOL: u64 = IncompatFlags::DEFAULT_SUBVOL.bits();
+pub const BTRFS_FEATURE_INCOMPAT_MIXED_GROUPS: u64 = IncompatFlags::MIXED_GROUPS.bits();
+pub const BTRFS_FEATURE_INCOMPAT_COMPRESS_LZO: u64 = IncompatFlags::COMPRESS_LZO.bits();
+pub const BTRFS_FEATURE_INCOMPAT_COMPRESS_ZSTD: u64 = IncompatFlags::COMPRESS_ZSTD.bits();
+pub const BTRFS_FEATURE_INCOMPAT_BIG_METADATA: u64 = IncompatFlags::BIG_METADATA.bits();
+pub const BTRFS_FEATURE_INCOMPAT_EXTENDED_IREF: u64 = IncompatFlags::EXTENDED_IREF.bits();
+pub const BTRFS_FEATURE_INCOMPAT_RAID56: u64 = IncompatFlags::RAID56.bits();
+pub const BTRFS_FEATURE_INCOMPAT_SKINNY_METADATA: u64 = IncompatFlags::SKINNY_METADATA.bits();
+pub const BTRFS_FEATURE_INCOMPAT_NO_HOLES: u64 = IncompatFlags::NO_HOLES.bits();
+pub const BTRFS_FEATURE_INCOMPAT_METADATA_UUID: u64 = IncompatFlags::METADATA_UUID.bits();
+pub const BTRFS_FEATURE_INCOMPAT_RAID1C34: u64 = IncompatFlags::RAID1C34.bits();
+pub const BTRFS_FEATURE_INCOMPAT_ZONED: u64 = IncompatFlags::ZONED.bits();
+pub const BTRFS_FEATURE_INCOMPAT_EXTENT_TREE_V2: u64 = IncompatFlags::EXTENT_TREE_V2.bits();
+pub const BTRFS_FEATURE_INCOMPAT_RAID_STRIPE_TREE: u64 = IncompatFlags::RAID_STRIPE_TREE.bits();
+pub const BTRFS_FEATURE_INCOMPAT_SIMPLE_QUOTA: u64 = IncompatFlags::SIMPLE_QUOTA.bits();
+
 // dev
 pub type btrfs_dev_extent = DevExtent;
+pub type btrfs_dev_replace_item = DevReplaceItem;
+pub type btrfs_dev_stats_item = DevStatsItem;
+
+pub const BTRFS_DEV_REPLACE_ITEM_CONT_READING_FROM_SRCDEV_MODE_ALWAYS: u64 =
+    ReplaceReadMode::Always as u64;
+pub const BTRFS_DEV_REPLACE_ITEM_CONT_READING_FROM_SRCDEV_MODE_AVOID: u64 =
+    ReplaceReadMode::Avoid as u64;
+
+pub const BTRFS_IOCTL_DEV_REPLACE_STATE_NEVER_STARTED: u64 = ReplaceState::NeverStarted as u64;
+pub const BTRFS_IOCTL_DEV_REPLACE_STATE_STARTED: u64 = ReplaceState::Started as u64;
+pub const BTRFS_IOCTL_DEV_REPLACE_STATE_FINISHED: u64 = ReplaceState::Finished as u64;
+pub const BTRFS_IOCTL_DEV_REPLACE_STATE_CANCELED: u64 = ReplaceState::Canceled as u64;
+pub const BTRFS_IOCTL_DEV_REPLACE_STATE_SUSPENDED: u64 = ReplaceState::Suspended as u64;
+
+pub const BTRFS_DEV_STAT_WRITE_ERRS: u8 = DevStatsCounter::WriteErrs as u8;
+pub const BTRFS_DEV_STAT_READ_ERRS: u8 = DevStatsCounter::ReadErrs as u8;
+pub const BTRFS_DEV_STAT_FLUSH_ERRS: u8 = DevStatsCounter::FlushErrs as u8;
+pub const BTRFS_DEV_STAT_CORRUPTION_ERRS: u8 = DevStatsCounter::CorruptionErrs as u8;
+pub const BTRFS_DEV_STAT_GENERATION_ERRS: u8 = DevStatsCounter::GenerationErrs as u8;
 
 // extent
 pub type btrfs_block_group_item = BlockGroupItem;
 pub type btrfs_extent_data_ref = ExtentDataRef;
 pub type btrfs_extent_inline_ref = ExtentInlineRefHeader;
+pub type btrfs_extent_item = ExtentItem;
 pub type btrfs_shared_data_ref = SharedDataRef;
+pub type btrfs_tree_block_info = TreeBlockInfo;
 
 pub const BTRFS_BLOCK_GROUP_DATA: u64 = AllocationType::DATA.bits();
 pub const BTRFS_BLOCK_GROUP_SYSTEM: u64 = AllocationType::SYSTEM.bits();
@@ -59,7 +233,37 @@ pub const BTRFS_BLOCK_GROUP_RAID6: u64 = ReplicationPolicy::RAID6.bits();
 pub const BTRFS_BLOCK_GROUP_RAID1C3: u64 = ReplicationPolicy::RAID1C3.bits();
 pub const BTRFS_BLOCK_GROUP_RAID1C4: u64 = ReplicationPolicy::RAID1C4.bits();
 
+pub const BTRFS_EXTENT_FLAG_DATA: u64 = ExtentFlags::DATA.bits();
+pub const BTRFS_EXTENT_FLAG_TREE_BLOCK: u64 = ExtentFlags::TREE_BLOCK.bits();
+pub const BTRFS_BLOCK_FLAG_FULL_BACKREF: u64 = ExtentFlags::FULL_BACKREF.bits();
+
 pub const BTRFS_TREE_BLOCK_REF_KEY: u8 = ExtentInlineRefType::TreeBlockRef as u8;
 pub const BTRFS_SHARED_BLOCK_REF_KEY: u8 = ExtentInlineRefType::SharedBlockRef as u8;
 pub const BTRFS_EXTENT_DATA_REF_KEY: u8 = ExtentInlineRefType::ExtentDataRef as u8;
 pub const BTRFS_SHARED_DATA_REF_KEY: u8 = ExtentInlineRefType::SharedDataRef as u8;
+
+// free space
+pub type btrfs_free_space_entry = FreeSpaceEntry;
+pub type btrfs_free_space_header = FreeSpaceHeader;
+pub type btrfs_free_space_info = FreeSpaceInfo;
+
+pub const BTRFS_FREE_SPACE_USING_BITMAPS: u32 = FreeSpaceInfoFlags::USING_BITMAPS.bits();
+pub const BTRFS_FREE_SPACE_EXTENT: u8 = FreeSpaceEntryType::Extent as u8;
+pub const BTRFS_FREE_SPACE_BITMAP: u8 = FreeSpaceEntryType::Bitmap as u8;
+
+// qgroup
+pub type btrfs_qgroup_info_item = QgroupInfoItem;
+pub type btrfs_qgroup_limit_item = QgroupLimitItem;
+pub type btrfs_qgroup_status_item = QgroupStatusItem;
+
+pub const BTRFS_QGROUP_STATUS_FLAG_ON: u64 = QgroupStatusFlags::ON.bits();
+pub const BTRFS_QGROUP_STATUS_FLAG_RESCAN: u64 = QgroupStatusFlags::RESCAN.bits();
+pub const BTRFS_QGROUP_STATUS_FLAG_INCONSISTENT: u64 = QgroupStatusFlags::INCONSISTENT.bits();
+pub const BTRFS_QGROUP_STATUS_FLAG_SIMPLE_MODE: u64 = QgroupStatusFlags::SIMPLE_MODE.bits();
+
+pub const BTRFS_QGROUP_LIMIT_MAX_RFER: u64 = QgroupLimitFlags::MAX_RFER.bits();
+pub const BTRFS_QGROUP_LIMIT_MAX_EXCL: u64 = QgroupLimitFlags::MAX_EXCL.bits();
+pub const BTRFS_QGROUP_LIMIT_RSV_RFER: u64 = QgroupLimitFlags::RSV_RFER.bits();
+pub const BTRFS_QGROUP_LIMIT_RSV_EXCL: u64 = QgroupLimitFlags::RSV_EXCL.bits();
+pub const BTRFS_QGROUP_LIMIT_RFER_CMPR: u64 = QgroupLimitFlags::RFER_CMPR.bits();
+pub const BTRFS_QGROUP_LIMIT_EXCL_CMPR: u64 = QgroupLimitFlags::EXCL_CMPR.bits();
@@ -0,0 +1,150 @@
+use crate::{ChecksumType, Checksummer, constants::CSUM_SIZE};
+
+const BLOCK_SIZE: usize = 128;
+
+/// The digest size used by btrfs, in bytes.
+const DIGEST_SIZE: usize = 32;
+
+const IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+const SIGMA: [[usize; 16]; 12] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+];
+
+/// Computes [`ChecksumType::BLAKE2b`] checksums. The digest is the unkeyed BLAKE2b hash with a
+/// 256-bit output.
+#[derive(Clone, Debug)]
+pub struct Blake2b {
+    state: [u64; 8],
+    total_len: u128,
+    buffer: [u8; BLOCK_SIZE],
+    buffer_len: usize,
+}
+
+impl Blake2b {
+    fn compress(&mut self, block: &[u8; BLOCK_SIZE], is_last: bool) {
+        let mut message = [0u64; 16];
+        for (word, bytes) in message.iter_mut().zip(block.chunks_exact(8)) {
+            *word = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+
+        let mut v = [0u64; 16];
+        v[..8].copy_from_slice(&self.state);
+        v[8..].copy_from_slice(&IV);
+        v[12] ^= self.total_len as u64;
+        v[13] ^= (self.total_len >> 64) as u64;
+        if is_last {
+            v[14] = !v[14];
+        }
+
+        for s in &SIGMA {
+            mix(&mut v, 0, 4, 8, 12, message[s[0]], message[s[1]]);
+            mix(&mut v, 1, 5, 9, 13, message[s[2]], message[s[3]]);
+            mix(&mut v, 2, 6, 10, 14, message[s[4]], message[s[5]]);
+            mix(&mut v, 3, 7, 11, 15, message[s[6]], message[s[7]]);
+            mix(&mut v, 0, 5, 10, 15, message[s[8]], message[s[9]]);
+            mix(&mut v, 1, 6, 11, 12, message[s[10]], message[s[11]]);
+            mix(&mut v, 2, 7, 8, 13, message[s[12]], message[s[13]]);
+            mix(&mut v, 3, 4, 9, 14, message[s[14]], message[s[15]]);
+        }
+
+        for (i, state) in self.state.iter_mut().enumerate() {
+            *state ^= v[i] ^ v[i + 8];
+        }
+    }
+}
+
+impl Checksummer for Blake2b {
+    const CHECKSUM_TYPE: ChecksumType = ChecksumType::BLAKE2b;
+
+    fn new() -> Self {
+        let mut state = IV;
+
+        // The parameter block only sets the digest size, with no key, and a fanout and depth of 1.
+        state[0] ^= 0x01010000 ^ DIGEST_SIZE as u64;
+
+        Self { state, total_len: 0, buffer: [0; BLOCK_SIZE], buffer_len: 0 }
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        // The final block must be compressed with the last block flag, so a full buffer is only
+        // compressed once more data arrives.
+        while !data.is_empty() {
+            if self.buffer_len == BLOCK_SIZE {
+                self.total_len += BLOCK_SIZE as u128;
+
+                let buffer = self.buffer;
+                self.compress(&buffer, false);
+                self.buffer_len = 0;
+            }
+
+            let len = data.len().min(BLOCK_SIZE - self.buffer_len);
+            self.buffer[self.buffer_len..self.buffer_len + len].copy_from_slice(&data[..len]);
+            self.buffer_len += len;
+            data = &data[len..];
+        }
+    }
+
+    fn finalize(mut self) -> [u8; CSUM_SIZE] {
+        self.total_len += self.buffer_len as u128;
+        self.buffer[self.buffer_len..].fill(0);
+
+        let buffer = self.buffer;
+        self.compress(&buffer, true);
+
+        let mut csum = [0; CSUM_SIZE];
+        for (bytes, word) in csum[..DIGEST_SIZE].chunks_exact_mut(8).zip(self.state) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+
+        csum
+    }
+}
+
+/// The `G` mixing function, which mixes two message words into four words of the working state.
+fn mix(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum::checksummer::test_vectors;
+
+    #[test]
+    fn known_answers() {
+        test_vectors::check::<Blake2b>([
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8",
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319",
+            "90a0bcf5e5a67ac1578c2754617994cfc248109275a809a0721feebd1e918738",
+            "e00b0ddbf1e2cdaf5c898e1a5e8826ea3a2c339bcf2a478da2e5fca9ff126672",
+        ]);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{ChecksumType, constants::CSUM_SIZE};
+
+/// An incremental implementation of one of the [`ChecksumType`] algorithms.
+///
+/// Checksums are returned padded with zeroes to [`CSUM_SIZE`], as they are stored in fields such
+/// as [`Header::csum`] and [`SuperBlock::csum`]. The first [`ChecksumType::digest_size`] bytes hold
+/// the digest, which is also the form stored in the checksum tree.
+///
+/// Each implementation is only available with its cargo feature enabled.
+///
+/// [`CSUM_SIZE`]: crate::constants::CSUM_SIZE
+/// [`Header::csum`]: crate::Header::csum
+/// [`SuperBlock::csum`]: crate::SuperBlock::csum
+pub trait Checksummer: Sized {
+    /// The checksum type that this implements.
+    const CHECKSUM_TYPE: ChecksumType;
+
+    /// Creates a checksummer with no data.
+    fn new() -> Self;
+
+    /// Adds data to the checksum.
+    fn update(&mut self, data: &[u8]);
+
+    /// Returns the checksum of all of the data, padded with zeroes to [`CSUM_SIZE`].
+    ///
+    /// [`CSUM_SIZE`]: crate::constants::CSUM_SIZE
+    fn finalize(self) -> [u8; CSUM_SIZE];
+
+    /// Returns the checksum of `data`, padded with zeroes to [`CSUM_SIZE`].
+    ///
+    /// [`CSUM_SIZE`]: crate::constants::CSUM_SIZE
+    fn checksum(data: &[u8]) -> [u8; CSUM_SIZE] {
+        let mut checksummer = Self::new();
+        checksummer.update(data);
+        checksummer.finalize()
+    }
+}
+
+/// Returns the checksum of `data` using `csum_type`, padded with zeroes to [`CSUM_SIZE`].
+///
+/// Returns `None` if the feature for `csum_type` is not enabled.
+///
+/// [`CSUM_SIZE`]: crate::constants::CSUM_SIZE
+#[allow(unused_variables)]
+pub fn checksum(csum_type: ChecksumType, data: &[u8]) -> Option<[u8; CSUM_SIZE]> {
+    match csum_type {
+        #[cfg(feature = "crc32c")]
+        ChecksumType::CRC32C => Some(crate::Crc32c::checksum(data)),
+        #[cfg(feature = "xxhash64")]
+        ChecksumType::XXHASH64 => Some(crate::XxHash64::checksum(data)),
+        #[cfg(feature = "sha256")]
+        ChecksumType::SHA256 => Some(crate::Sha256::checksum(data)),
+        #[cfg(feature = "blake2b")]
+        ChecksumType::BLAKE2b => Some(crate::Blake2b::checksum(data)),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
+/// Known-answer vectors shared by the tests of each [`Checksummer`].
+#[cfg(all(
+    test,
+    any(feature = "blake2b", feature = "crc32c", feature = "sha256", feature = "xxhash64")
+))]
+pub(crate) mod test_vectors {
+    use super::*;
+
+    /// The two-block message from the SHA-2 test vectors, which crosses the 32-byte xxHash64
+    /// stripe and the 64-byte SHA-256 block.
+    const MULTI_BLOCK: &[u8] = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmno\
+        ijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+
+    /// A message that spans several 128-byte BLAKE2b blocks.
+    const LONG: &[u8] = &[b'a'; 1000];
+
+    const INPUTS: [&[u8]; 4] = [b"", b"abc", MULTI_BLOCK, LONG];
+
+    /// The sizes that input is split into when checking the incremental path, chosen to land on
+    /// either side of every block boundary.
+    const CHUNK_SIZES: [usize; 9] = [1, 7, 31, 32, 63, 64, 65, 128, 129];
+
+    /// Checks that `C` produces the expected digests, given as hexadecimal, for `""`, `"abc"`, a
+    /// two-block message and 1000 `'a'` bytes. Each input is checksummed both at once and in
+    /// chunks.
+    pub(crate) fn check<C: Checksummer>(expected: [&str; 4]) {
+        assert_eq!(MULTI_BLOCK.len(), 112);
+
+        for (input, expected) in INPUTS.iter().zip(expected) {
+            let mut expected_csum = [0; CSUM_SIZE];
+            for (byte, hex) in expected_csum.iter_mut().zip(expected.as_bytes().chunks_exact(2)) {
+                *byte = u8::from_str_radix(core::str::from_utf8(hex).unwrap(), 16).unwrap();
+            }
+
+            assert_eq!(expected.len(), C::CHECKSUM_TYPE.digest_size() * 2);
+            assert_eq!(C::checksum(input), expected_csum, "input of {} bytes", input.len());
+            assert_eq!(checksum(C::CHECKSUM_TYPE, input), Some(expected_csum));
+
+            for chunk_size in CHUNK_SIZES {
+                let mut checksummer = C::new();
+                for chunk in input.chunks(chunk_size) {
+                    checksummer.update(chunk);
+                }
+
+                assert_eq!(
+                    checksummer.finalize(),
+                    expected_csum,
+                    "input of {} bytes in chunks of {chunk_size}",
+                    input.len()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{ChecksumType, Checksummer, constants::CSUM_SIZE, crc32c::crc32c};
+
+/// Computes [`ChecksumType::CRC32C`] checksums. The digest is the standard CRC-32C, stored in
+/// little-endian order.
+#[derive(Copy, Clone, Debug)]
+pub struct Crc32c {
+    crc: u32,
+}
+
+impl Checksummer for Crc32c {
+    const CHECKSUM_TYPE: ChecksumType = ChecksumType::CRC32C;
+
+    fn new() -> Self {
+        Self { crc: !0 }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.crc = crc32c(self.crc, data);
+    }
+
+    fn finalize(self) -> [u8; CSUM_SIZE] {
+        let mut csum = [0; CSUM_SIZE];
+        csum[..4].copy_from_slice(&(!self.crc).to_le_bytes());
+        csum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum::checksummer::test_vectors;
+
+    #[test]
+    fn known_answers() {
+        // The digest is stored in little-endian order.
+        test_vectors::check::<Crc32c>(["00000000", "b73f4b36", "b9a4603f", "6aef199f"]);
+    }
+}
@@ -0,0 +1,19 @@
+#[cfg(feature = "blake2b")]
+mod blake2b;
+mod checksummer;
+#[cfg(feature = "crc32c")]
+mod crc32c;
+#[cfg(feature = "sha256")]
+mod sha256;
+#[cfg(feature = "xxhash64")]
+mod xxhash64;
+
+#[cfg(feature = "blake2b")]
+pub use blake2b::*;
+pub use checksummer::*;
+#[cfg(feature = "crc32c")]
+pub use crc32c::*;
+#[cfg(feature = "sha256")]
+pub use sha256::*;
+#[cfg(feature = "xxhash64")]
+pub use xxhash64::*;
@@ -0,0 +1,143 @@
+use crate::{ChecksumType, Checksummer, constants::CSUM_SIZE};
+
+const PRIME_1: u64 = 0x9E3779B185EBCA87;
+const PRIME_2: u64 = 0xC2B2AE3D27D4EB4F;
+const PRIME_3: u64 = 0x165667B19E3779F9;
+const PRIME_4: u64 = 0x85EBCA77C2B2AE63;
+const PRIME_5: u64 = 0x27D4EB2F165667C5;
+
+const STRIPE_SIZE: usize = 32;
+
+/// Computes [`ChecksumType::XXHASH64`] checksums. The digest is the 64-bit xxHash with a seed of
+/// 0, stored in little-endian order.
+#[derive(Clone, Debug)]
+pub struct XxHash64 {
+    accumulators: [u64; 4],
+    total_len: u64,
+    buffer: [u8; STRIPE_SIZE],
+    buffer_len: usize,
+}
+
+impl XxHash64 {
+    fn round(accumulator: u64, lane: u64) -> u64 {
+        accumulator.wrapping_add(lane.wrapping_mul(PRIME_2)).rotate_left(31).wrapping_mul(PRIME_1)
+    }
+
+    fn merge_round(hash: u64, accumulator: u64) -> u64 {
+        (hash ^ Self::round(0, accumulator)).wrapping_mul(PRIME_1).wrapping_add(PRIME_4)
+    }
+
+    fn consume_stripe(&mut self, stripe: &[u8]) {
+        for (accumulator, lane) in self.accumulators.iter_mut().zip(stripe.chunks_exact(8)) {
+            *accumulator = Self::round(*accumulator, read_u64(lane));
+        }
+    }
+}
+
+impl Checksummer for XxHash64 {
+    const CHECKSUM_TYPE: ChecksumType = ChecksumType::XXHASH64;
+
+    fn new() -> Self {
+        Self {
+            accumulators: [PRIME_1.wrapping_add(PRIME_2), PRIME_2, 0, 0u64.wrapping_sub(PRIME_1)],
+            total_len: 0,
+            buffer: [0; STRIPE_SIZE],
+            buffer_len: 0,
+        }
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        self.total_len += data.len() as u64;
+
+        if self.buffer_len > 0 {
+            let len = data.len().min(STRIPE_SIZE - self.buffer_len);
+            self.buffer[self.buffer_len..self.buffer_len + len].copy_from_slice(&data[..len]);
+            self.buffer_len += len;
+            data = &data[len..];
+
+            if self.buffer_len < STRIPE_SIZE {
+                return;
+            }
+
+            let buffer = self.buffer;
+            self.consume_stripe(&buffer);
+            self.buffer_len = 0;
+        }
+
+        let mut stripes = data.chunks_exact(STRIPE_SIZE);
+        for stripe in &mut stripes {
+            self.consume_stripe(stripe);
+        }
+
+        let rest = stripes.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffer_len = rest.len();
+    }
+
+    fn finalize(self) -> [u8; CSUM_SIZE] {
+        let mut hash = if self.total_len >= STRIPE_SIZE as u64 {
+            let [v1, v2, v3, v4] = self.accumulators;
+            let hash = v1
+                .rotate_left(1)
+                .wrapping_add(v2.rotate_left(7))
+                .wrapping_add(v3.rotate_left(12))
+                .wrapping_add(v4.rotate_left(18));
+
+            self.accumulators.iter().fold(hash, |hash, &v| Self::merge_round(hash, v))
+        } else {
+            PRIME_5
+        };
+
+        hash = hash.wrapping_add(self.total_len);
+
+        let mut rest = &self.buffer[..self.buffer_len];
+        while rest.len() >= 8 {
+            hash ^= Self::round(0, read_u64(rest));
+            hash = hash.rotate_left(27).wrapping_mul(PRIME_1).wrapping_add(PRIME_4);
+            rest = &rest[8..];
+        }
+
+        if rest.len() >= 4 {
+            let lane = u32::from_le_bytes(rest[..4].try_into().unwrap()) as u64;
+            hash ^= lane.wrapping_mul(PRIME_1);
+            hash = hash.rotate_left(23).wrapping_mul(PRIME_2).wrapping_add(PRIME_3);
+            rest = &rest[4..];
+        }
+
+        for &byte in rest {
+            hash ^= (byte as u64).wrapping_mul(PRIME_5);
+            hash = hash.rotate_left(11).wrapping_mul(PRIME_1);
+        }
+
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(PRIME_2);
+        hash ^= hash >> 29;
+        hash = hash.wrapping_mul(PRIME_3);
+        hash ^= hash >> 32;
+
+        let mut csum = [0; CSUM_SIZE];
+        csum[..8].copy_from_slice(&hash.to_le_bytes());
+        csum
+    }
+}
+
+fn read_u64(bytes: &[u8]) -> u64 {
+    u64::from_le_bytes(bytes[..8].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum::checksummer::test_vectors;
+
+    #[test]
+    fn known_answers() {
+        // The digest is stored in little-endian order.
+        test_vectors::check::<XxHash64>([
+            "99e9d85137db46ef",
+            "990977adf52cbc44",
+            "211ded2d1202fcba",
+            "2342da2e713be456",
+        ]);
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{AllocationType, Key, KeyType, ObjectId, ReplicationPolicy};
+use bitflags::bitflags;
+use static_assertions::const_assert_eq;
+use zerocopy::little_endian::{U32 as U32LE, U64 as U64LE};
+use zerocopy_derive::*;
+
+/// Contains the state of a balance operation. This is stored in the root tree with a key of
+/// ([`ObjectId::BALANCE`], `TEMPORARY_ITEM`, 0) while a balance is running or paused, and is
+/// removed once it completes or is canceled.
+#[derive(Copy, Clone, Debug, Hash, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct BalanceItem {
+    /// Flags for the balance. See [`BalanceFlags`] for values.
+    pub flags: U64LE,
+
+    /// The filters applied to data chunks.
+    pub data: BalanceArgs,
+
+    /// The filters applied to metadata chunks.
+    pub meta: BalanceArgs,
+
+    /// The filters applied to system chunks.
+    pub sys: BalanceArgs,
+
+    /// Reserved for future use.
+    pub _unused: [U64LE; 4],
+}
+const_assert_eq!(core::mem::size_of::<BalanceItem>(), 448);
+
+impl BalanceItem {
+    /// Returns the key of the balance item.
+    pub fn key() -> Key {
+        Key::new(ObjectId::BALANCE.0, KeyType::TemporaryItem, 0)
+    }
+
+    /// Returns whether the key is the key of the balance item.
+    pub fn is_balance_key(key: &Key) -> bool {
+        key.objectid.get() == ObjectId::BALANCE.0
+            && key.typed_key_type() == Ok(KeyType::TemporaryItem)
+    }
+
+    /// Returns the [`flags`] as [`BalanceFlags`]. Unknown bits are retained.
+    ///
+    /// [`flags`]: BalanceItem::flags
+    pub fn typed_flags(&self) -> BalanceFlags {
+        BalanceFlags::from_bits_retain(self.flags.get())
+    }
+}
+
+/// The filters a balance applies to one type of chunk.
+///
+/// Each filter only applies if the matching flag is set in [`flags`].
+///
+/// [`flags`]: BalanceArgs::flags
+#[derive(Copy, Clone, Debug, Hash, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct BalanceArgs {
+    /// Only balance chunks with one of these replication policies.
+    pub profiles: U64LE,
+
+    /// Only balance chunks whose usage is below this percentage.
+    ///
+    /// With [`BalanceArgsFlags::USAGE_RANGE`], this holds a range instead. See
+    /// [`usage_range`](BalanceArgs::usage_range).
+    pub usage: U64LE,
+
+    /// Only balance chunks with a stripe on this device.
+    pub devid: U64LE,
+
+    /// The start of the physical range on [`devid`](BalanceArgs::devid) to balance.
+    pub pstart: U64LE,
+
+    /// The end of the physical range on [`devid`](BalanceArgs::devid) to balance.
+    pub pend: U64LE,
+
+    /// The start of the logical range to balance.
+    pub vstart: U64LE,
+
+    /// The end of the logical range to balance.
+    pub vend: U64LE,
+
+    /// The replication policy to convert chunks to.
+    pub target: U64LE,
+
+    /// Flags indicating which filters are set. See [`BalanceArgsFlags`] for values.
+    pub flags: U64LE,
+
+    /// The maximum number of chunks to balance.
+    ///
+    /// With [`BalanceArgsFlags::LIMIT_RANGE`], this holds a range instead. See
+    /// [`limit_range`](BalanceArgs::limit_range).
+    pub limit: U64LE,
+
+    /// Only balance chunks with at least this many stripes.
+    pub stripes_min: U32LE,
+
+    /// Only balance chunks with at most this many stripes.
+    pub stripes_max: U32LE,
+
+    /// Reserved for future use.
+    pub _unused: [U64LE; 6],
+}
+const_assert_eq!(core::mem::size_of::<BalanceArgs>(), 136);
+
+impl BalanceArgs {
+    /// Returns the [`flags`] as [`BalanceArgsFlags`]. Unknown bits are retained.
+    ///
+    /// [`flags`]: BalanceArgs::flags
+    pub fn typed_flags(&self) -> BalanceArgsFlags {
+        BalanceArgsFlags::from_bits_retain(self.flags.get())
+    }
+
+    /// Returns the [`profiles`] as a [`ReplicationPolicy`]. Unknown bits are retained.
+    ///
+    /// [`profiles`]: BalanceArgs::profiles
+    pub fn typed_profiles(&self) -> ReplicationPolicy {
+        ReplicationPolicy::from_bits_retain(self.profiles.get())
+    }
+
+    /// Returns the [`target`] as a [`ReplicationPolicy`]. Unknown bits are retained.
+    ///
+    /// [`target`]: BalanceArgs::target
+    pub fn typed_target(&self) -> ReplicationPolicy {
+        ReplicationPolicy::from_bits_retain(self.target.get())
+    }
+
+    /// Returns the minimum and maximum usage percentages stored in [`usage`] when
+    /// [`BalanceArgsFlags::USAGE_RANGE`] is set.
+    ///
+    /// [`usage`]: BalanceArgs::usage
+    pub fn usage_range(&self) -> (u32, u32) {
+        split_range(self.usage.get())
+    }
+
+    /// Returns the minimum and maximum number of chunks stored in [`limit`] when
+    /// [`BalanceArgsFlags::LIMIT_RANGE`] is set.
+    ///
+    /// [`limit`]: BalanceArgs::limit
+    pub fn limit_range(&self) -> (u32, u32) {
+        split_range(self.limit.get())
+    }
+}
+
+/// Splits a 64-bit field into the two 32-bit values that share its storage.
+fn split_range(value: u64) -> (u32, u32) {
+    (value as u32, (value >> 32) as u32)
+}
+
+bitflags! {
+    /// The flags of a [`BalanceItem`].
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct BalanceFlags: u64 {
+        /// Data chunks are balanced using [`BalanceItem::data`].
+        const DATA = AllocationType::DATA.bits();
+
+        /// System chunks are balanced using [`BalanceItem::sys`].
+        const SYSTEM = AllocationType::SYSTEM.bits();
+
+        /// Metadata chunks are balanced using [`BalanceItem::meta`].
+        const METADATA = AllocationType::METADATA.bits();
+
+        /// Allow conversions that reduce the redundancy of metadata.
+        const FORCE = 0x8;
+
+        /// The balance was interrupted and should be resumed.
+        const RESUME = 0x10;
+    }
+}
+
+bitflags! {
+    /// The flags of a [`BalanceArgs`].
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct BalanceArgsFlags: u64 {
+        /// Filter by [`BalanceArgs::profiles`].
+        const PROFILES = 0x1;
+
+        /// Filter by [`BalanceArgs::usage`].
+        const USAGE = 0x2;
+
+        /// Filter by [`BalanceArgs::devid`].
+        const DEVID = 0x4;
+
+        /// Filter by [`BalanceArgs::pstart`] and [`BalanceArgs::pend`].
+        const DRANGE = 0x8;
+
+        /// Filter by [`BalanceArgs::vstart`] and [`BalanceArgs::vend`].
+        const VRANGE = 0x10;
+
+        /// Limit the number of chunks by [`BalanceArgs::limit`].
+        const LIMIT = 0x20;
+
+        /// Limit the number of chunks by [`BalanceArgs::limit_range`].
+        const LIMIT_RANGE = 0x40;
+
+        /// Filter by [`BalanceArgs::stripes_min`] and [`BalanceArgs::stripes_max`].
+        const STRIPES_RANGE = 0x80;
+
+        /// Convert chunks to [`BalanceArgs::target`].
+        const CONVERT = 0x100;
+
+        /// Skip chunks that already have the [`target`](BalanceArgs::target) profile.
+        const SOFT = 0x200;
+
+        /// Filter by [`BalanceArgs::usage_range`].
+        const USAGE_RANGE = 0x400;
+    }
+}
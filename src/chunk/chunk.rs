@@ -1,4 +1,4 @@
-use crate::Stripe;
+use crate::{AllocationType, BlockGroupProfile, Stripe};
 use static_assertions::const_assert_eq;
 use zerocopy::{
     CastError, FromBytes as _,
@@ -78,6 +78,21 @@ impl Chunk {
 
         ChunkDynamic::ref_from_prefix_with_elems(bytes, num_stripes).map(|(chunk, _)| chunk)
     }
+
+    /// Returns the allocation type bits of the [`chunk_type`]. Other bits are discarded.
+    ///
+    /// [`chunk_type`]: Chunk::chunk_type
+    pub fn allocation_type(&self) -> AllocationType {
+        AllocationType::from_bits_truncate(self.chunk_type.get())
+    }
+
+    /// Returns the replication profile of the [`chunk_type`]. Returns `None` if more than one
+    /// profile is set.
+    ///
+    /// [`chunk_type`]: Chunk::chunk_type
+    pub fn profile(&self) -> Option<BlockGroupProfile> {
+        BlockGroupProfile::from_flags(self.chunk_type.get())
+    }
 }
 
 /// This structure contains the mapping from a virtualized usable byte range within the backing
@@ -118,3 +133,20 @@ pub struct ChunkDynamic {
     /// The first of one or more stripes that map to device extents.
     pub stripe: [Stripe],
 }
+
+impl ChunkDynamic {
+    /// Returns the allocation type bits of the [`chunk_type`]. Other bits are discarded.
+    ///
+    /// [`chunk_type`]: ChunkDynamic::chunk_type
+    pub fn allocation_type(&self) -> AllocationType {
+        AllocationType::from_bits_truncate(self.chunk_type.get())
+    }
+
+    /// Returns the replication profile of the [`chunk_type`]. Returns `None` if more than one
+    /// profile is set.
+    ///
+    /// [`chunk_type`]: ChunkDynamic::chunk_type
+    pub fn profile(&self) -> Option<BlockGroupProfile> {
+        BlockGroupProfile::from_flags(self.chunk_type.get())
+    }
+}
@@ -1,6 +1,8 @@
+mod balance_item;
 #[allow(clippy::module_inception)]
 mod chunk;
 mod stripe;
 
+pub use balance_item::*;
 pub use chunk::*;
 pub use stripe::*;
@@ -23,3 +23,13 @@ pub const MAX_SYSTEM_CHUNK_ARRAY_SIZE: usize = 2048;
 
 /// Corresponds to `BTRFS_NUM_BACKUP_ROOTS`.
 pub const NUM_BACKUP_ROOTS: usize = 4;
+
+/// Corresponds to `BTRFS_DEV_REPLACE_DEVID`. This is the device ID of the target device of a
+/// running or suspended device replace operation.
+pub const DEV_REPLACE_DEVID: u64 = 0;
+
+/// Corresponds to `BTRFS_AVAIL_ALLOC_BIT_SINGLE`. This bit explicitly marks the single profile in
+/// block group flags, where no [`ReplicationPolicy`] bit is otherwise set.
+///
+/// [`ReplicationPolicy`]: crate::ReplicationPolicy
+pub const AVAIL_ALLOC_BIT_SINGLE: u64 = 1 << 48;
@@ -1,4 +1,4 @@
-use crate::UuidBytes;
+use crate::{UuidBytes, constants::DEV_REPLACE_DEVID};
 use static_assertions::const_assert_eq;
 use zerocopy::little_endian::{U32 as U32LE, U64 as U64LE};
 use zerocopy_derive::*;
@@ -55,3 +55,11 @@ pub struct DevItem {
     pub fsid: UuidBytes,
 }
 const_assert_eq!(core::mem::size_of::<DevItem>(), 98);
+
+impl DevItem {
+    /// Returns whether this is the target device of a device replace operation, which uses the
+    /// reserved device ID [`DEV_REPLACE_DEVID`] until the replace completes.
+    pub fn is_replace_target(&self) -> bool {
+        self.devid.get() == DEV_REPLACE_DEVID
+    }
+}
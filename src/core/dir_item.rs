@@ -0,0 +1,141 @@
+use crate::Key;
+use num_enum::{IntoPrimitive, TryFromPrimitive, TryFromPrimitiveError};
+use static_assertions::const_assert_eq;
+use strum::EnumIter;
+use zerocopy::{
+    FromBytes as _,
+    little_endian::{U16 as U16LE, U64 as U64LE},
+};
+use zerocopy_derive::*;
+
+/// A directory entry or extended attribute. This is used by `DIR_ITEM`, `DIR_INDEX` and
+/// `XATTR_ITEM` items.
+///
+/// The name of the entry is stored immediately after the struct, followed by the data. The data
+/// is only used by extended attributes, where it contains the attribute value.
+///
+/// A `DIR_ITEM` or `XATTR_ITEM` may contain multiple entries back-to-back when their name hashes
+/// collide. To walk all of them, see [`DirItemIter`].
+#[derive(Copy, Clone, Debug, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct DirItem {
+    /// The key of the item that the entry points to. This is either an inode item or, for
+    /// subvolumes, a root item. For extended attributes, this is zeroed out.
+    pub location: Key,
+
+    /// The transaction ID of the transaction that created this entry.
+    pub transid: U64LE,
+
+    /// The length of the data, stored after the name.
+    pub data_len: U16LE,
+
+    /// The length of the name, stored after this struct.
+    pub name_len: U16LE,
+
+    /// The type of the entry, which should correspond with a value from [`DirEntryType`].
+    pub dir_type: u8,
+}
+const_assert_eq!(core::mem::size_of::<DirItem>(), 30);
+
+impl DirItem {
+    /// Returns the [`dir_type`] as a [`DirEntryType`], if it is a known value.
+    ///
+    /// [`dir_type`]: DirItem::dir_type
+    pub fn typed_dir_type(&self) -> Result<DirEntryType, TryFromPrimitiveError<DirEntryType>> {
+        DirEntryType::try_from(self.dir_type)
+    }
+}
+
+/// The type of the object a [`DirItem`] points to.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    EnumIter,
+    IntoPrimitive,
+    TryFromPrimitive,
+    IntoBytes,
+    TryFromBytes,
+    Unaligned,
+    KnownLayout,
+    Immutable,
+)]
+#[repr(u8)]
+pub enum DirEntryType {
+    Unknown = 0,
+    RegularFile = 1,
+    Directory = 2,
+    CharDevice = 3,
+    BlockDevice = 4,
+    Fifo = 5,
+    Socket = 6,
+    Symlink = 7,
+
+    /// The entry is an extended attribute.
+    Xattr = 8,
+}
+const_assert_eq!(core::mem::size_of::<DirEntryType>(), 1);
+
+/// A single entry yielded by [`DirItemIter`].
+#[derive(Copy, Clone, Debug)]
+pub struct DirItemEntry<'a> {
+    /// The fixed-size part of the entry.
+    pub item: &'a DirItem,
+
+    /// The name of the entry.
+    pub name: &'a [u8],
+
+    /// The data of the entry. This contains the value of extended attributes and is empty
+    /// otherwise.
+    pub data: &'a [u8],
+}
+
+/// An iterator over the [`DirItem`] entries packed into the data of a single item.
+///
+/// Iteration stops early if the remaining bytes are too short to hold a complete entry. Those
+/// bytes can be retrieved with [`remainder`].
+///
+/// [`remainder`]: DirItemIter::remainder
+#[derive(Clone, Debug)]
+pub struct DirItemIter<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> DirItemIter<'a> {
+    /// Creates an iterator over the entries in the data of a `DIR_ITEM`, `DIR_INDEX` or
+    /// `XATTR_ITEM` item.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the bytes that have not been parsed yet. This is empty once iteration has
+    /// completed over well-formed data.
+    pub fn remainder(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> Iterator for DirItemIter<'a> {
+    type Item = DirItemEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (item, rest) = DirItem::ref_from_prefix(self.bytes).ok()?;
+
+        let name_len = item.name_len.get() as usize;
+        let data_len = item.data_len.get() as usize;
+        if rest.len() < name_len + data_len {
+            return None;
+        }
+
+        let (name, rest) = rest.split_at(name_len);
+        let (data, rest) = rest.split_at(data_len);
+        self.bytes = rest;
+
+        Some(DirItemEntry { item, name, data })
+    }
+}
+
+impl core::iter::FusedIterator for DirItemIter<'_> {}
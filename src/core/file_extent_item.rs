@@ -0,0 +1,199 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive, TryFromPrimitiveError};
+use static_assertions::const_assert_eq;
+use strum::EnumIter;
+use zerocopy::{
+    FromBytes as _,
+    little_endian::{U16 as U16LE, U64 as U64LE},
+};
+use zerocopy_derive::*;
+
+/// Describes a range of file data. This is the fixed part of an `EXTENT_DATA` item, where the
+/// key's offset is the byte offset of the range within the file.
+///
+/// What follows this struct depends on [`extent_type`]:
+///
+///  * For [`FileExtentType::Inline`], the file data is stored immediately after the struct until
+///    the end of the item.
+///  * For [`FileExtentType::Regular`] and [`FileExtentType::Prealloc`], a [`FileExtentRegular`]
+///    follows, which references the data on disk.
+///
+/// To parse both parts at once, see [`FileExtent::parse`].
+///
+/// [`extent_type`]: FileExtentItem::extent_type
+#[derive(Copy, Clone, Debug, Hash, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct FileExtentItem {
+    /// The transaction ID of the transaction that allocated this extent.
+    pub generation: U64LE,
+
+    /// The size of the extent's data after decompression, in bytes.
+    ///
+    /// For inline extents, this is the size of the file data. For regular extents, this is the
+    /// size of the entire decompressed extent, which may be larger than the range referenced by
+    /// this item.
+    pub ram_bytes: U64LE,
+
+    /// The compression algorithm, which should correspond with a value from [`CompressionType`].
+    pub compression: u8,
+
+    /// The encryption algorithm. This is currently always 0.
+    pub encryption: u8,
+
+    /// Reserved for other encodings. This is currently always 0.
+    pub other_encoding: U16LE,
+
+    /// The type of the extent, which should correspond with a value from [`FileExtentType`].
+    pub extent_type: u8,
+}
+const_assert_eq!(core::mem::size_of::<FileExtentItem>(), 21);
+
+impl FileExtentItem {
+    /// Returns the [`compression`] as a [`CompressionType`], if it is a known value.
+    ///
+    /// [`compression`]: FileExtentItem::compression
+    pub fn typed_compression(
+        &self,
+    ) -> Result<CompressionType, TryFromPrimitiveError<CompressionType>> {
+        CompressionType::try_from(self.compression)
+    }
+
+    /// Returns the [`extent_type`] as a [`FileExtentType`], if it is a known value.
+    ///
+    /// [`extent_type`]: FileExtentItem::extent_type
+    pub fn typed_extent_type(
+        &self,
+    ) -> Result<FileExtentType, TryFromPrimitiveError<FileExtentType>> {
+        FileExtentType::try_from(self.extent_type)
+    }
+}
+
+/// Locates the data of a regular or preallocated extent on disk. This immediately follows a
+/// [`FileExtentItem`] whose type is [`FileExtentType::Regular`] or [`FileExtentType::Prealloc`].
+///
+/// Together, [`disk_bytenr`] and [`disk_num_bytes`] identify the extent in the extent tree, which
+/// may be shared by several files. [`offset`] and [`num_bytes`] select the range of the
+/// decompressed extent that is part of this file.
+///
+/// [`disk_bytenr`]: FileExtentRegular::disk_bytenr
+/// [`disk_num_bytes`]: FileExtentRegular::disk_num_bytes
+/// [`offset`]: FileExtentRegular::offset
+/// [`num_bytes`]: FileExtentRegular::num_bytes
+#[derive(Copy, Clone, Debug, Hash, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct FileExtentRegular {
+    /// The logical address of the extent. If this is 0, the extent is a hole.
+    pub disk_bytenr: U64LE,
+
+    /// The size of the extent on disk, in bytes.
+    pub disk_num_bytes: U64LE,
+
+    /// The offset within the decompressed extent where the data of this file starts.
+    pub offset: U64LE,
+
+    /// The number of bytes of the decompressed extent that are part of this file.
+    pub num_bytes: U64LE,
+}
+const_assert_eq!(core::mem::size_of::<FileExtentRegular>(), 32);
+
+impl FileExtentRegular {
+    /// Returns whether this extent is a hole, which has no data on disk.
+    pub fn is_hole(&self) -> bool {
+        self.disk_bytenr.get() == 0
+    }
+}
+
+/// A parsed `EXTENT_DATA` item.
+#[derive(Copy, Clone, Debug)]
+pub enum FileExtent<'a> {
+    /// The file data is stored within the item.
+    Inline {
+        item: &'a FileExtentItem,
+
+        /// The inline data, which is compressed if [`FileExtentItem::compression`] is set.
+        data: &'a [u8],
+    },
+
+    /// The file data is stored in an extent on disk. This is used for both regular and
+    /// preallocated extents, which can be told apart by [`FileExtentItem::extent_type`].
+    OnDisk { item: &'a FileExtentItem, extent: &'a FileExtentRegular },
+}
+
+impl<'a> FileExtent<'a> {
+    /// Parses the data of an `EXTENT_DATA` item.
+    ///
+    /// Returns `None` if the extent type is unknown or the data is too short for it.
+    pub fn parse(bytes: &'a [u8]) -> Option<Self> {
+        let (item, rest) = FileExtentItem::ref_from_prefix(bytes).ok()?;
+
+        match item.typed_extent_type().ok()? {
+            FileExtentType::Inline => Some(FileExtent::Inline { item, data: rest }),
+            FileExtentType::Regular | FileExtentType::Prealloc => {
+                let (extent, _) = FileExtentRegular::ref_from_prefix(rest).ok()?;
+                Some(FileExtent::OnDisk { item, extent })
+            }
+        }
+    }
+
+    /// Returns the fixed part of the item.
+    pub fn item(&self) -> &'a FileExtentItem {
+        match self {
+            FileExtent::Inline { item, .. } | FileExtent::OnDisk { item, .. } => item,
+        }
+    }
+}
+
+/// The compression algorithm used for file data.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    EnumIter,
+    IntoPrimitive,
+    TryFromPrimitive,
+    IntoBytes,
+    TryFromBytes,
+    Unaligned,
+    KnownLayout,
+    Immutable,
+)]
+#[repr(u8)]
+pub enum CompressionType {
+    None = 0,
+    Zlib = 1,
+    Lzo = 2,
+    Zstd = 3,
+}
+const_assert_eq!(core::mem::size_of::<CompressionType>(), 1);
+
+/// The type of a [`FileExtentItem`].
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    EnumIter,
+    IntoPrimitive,
+    TryFromPrimitive,
+    IntoBytes,
+    TryFromBytes,
+    Unaligned,
+    KnownLayout,
+    Immutable,
+)]
+#[repr(u8)]
+pub enum FileExtentType {
+    /// The file data is stored inline within the item.
+    Inline = 0,
+
+    /// The file data is stored in an extent on disk.
+    Regular = 1,
+
+    /// The extent has been allocated on disk but not written to. Reads return zeroes.
+    Prealloc = 2,
+}
+const_assert_eq!(core::mem::size_of::<FileExtentType>(), 1);
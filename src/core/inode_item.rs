@@ -1,6 +1,8 @@
-use crate::Time;
+use crate::{DirEntryType, Time};
 use bitflags::bitflags;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
 use static_assertions::const_assert_eq;
+use strum::EnumIter;
 use zerocopy::little_endian::{U32 as U32LE, U64 as U64LE};
 use zerocopy_derive::*;
 
@@ -39,10 +41,11 @@ pub struct InodeItem {
     /// The group ID of the group owner in Unix.
     pub gid: U32LE,
 
-    /// The Unix protection mode.
+    /// The Unix protection mode, which holds the [`FileType`] and [`InodePermissions`].
     pub mode: U32LE,
 
-    /// The device identifier (if a special file).
+    /// The device identifier (if a special file). This uses the Linux kernel's internal
+    /// encoding, where the major number is stored above the lower 20 bits.
     pub rdev: U64LE,
 
     /// Flags for the inode. See [InodeFlags] for values.
@@ -71,6 +74,128 @@ pub struct InodeItem {
 }
 const_assert_eq!(core::mem::size_of::<InodeItem>(), 160);
 
+impl InodeItem {
+    const RDEV_MINOR_BITS: u32 = 20;
+
+    /// Returns the file type from the [`mode`], if it is a known value.
+    ///
+    /// [`mode`]: InodeItem::mode
+    pub fn file_type(&self) -> Option<FileType> {
+        FileType::from_mode(self.mode.get())
+    }
+
+    /// Returns the type that a [`DirItem`] pointing to this inode carries. This is
+    /// [`DirEntryType::Unknown`] if the file type is not known.
+    ///
+    /// [`DirItem`]: crate::DirItem
+    pub fn dir_type(&self) -> DirEntryType {
+        self.file_type().map_or(DirEntryType::Unknown, DirEntryType::from)
+    }
+
+    /// Returns the permission bits of the [`mode`].
+    ///
+    /// [`mode`]: InodeItem::mode
+    pub fn permissions(&self) -> InodePermissions {
+        InodePermissions::from_bits_truncate(self.mode.get())
+    }
+
+    /// Returns the major number of the [`rdev`].
+    ///
+    /// [`rdev`]: InodeItem::rdev
+    pub fn rdev_major(&self) -> u32 {
+        (self.rdev.get() >> Self::RDEV_MINOR_BITS) as u32
+    }
+
+    /// Returns the minor number of the [`rdev`].
+    ///
+    /// [`rdev`]: InodeItem::rdev
+    pub fn rdev_minor(&self) -> u32 {
+        (self.rdev.get() & ((1 << Self::RDEV_MINOR_BITS) - 1)) as u32
+    }
+}
+
+/// The type of a file, as stored in the `S_IFMT` bits of [`InodeItem::mode`].
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, EnumIter, IntoPrimitive, TryFromPrimitive)]
+#[repr(u32)]
+pub enum FileType {
+    Fifo = 0o010000,
+    CharDevice = 0o020000,
+    Directory = 0o040000,
+    BlockDevice = 0o060000,
+    RegularFile = 0o100000,
+    Symlink = 0o120000,
+    Socket = 0o140000,
+}
+
+impl FileType {
+    /// The bits of a mode that hold the file type. Corresponds to `S_IFMT`.
+    pub const MASK: u32 = 0o170000;
+
+    /// Decodes the file type from a Unix mode. Returns `None` if the type bits are not a known
+    /// value.
+    pub fn from_mode(mode: u32) -> Option<Self> {
+        Self::try_from(mode & Self::MASK).ok()
+    }
+}
+
+impl From<FileType> for DirEntryType {
+    fn from(value: FileType) -> Self {
+        match value {
+            FileType::Fifo => DirEntryType::Fifo,
+            FileType::CharDevice => DirEntryType::CharDevice,
+            FileType::Directory => DirEntryType::Directory,
+            FileType::BlockDevice => DirEntryType::BlockDevice,
+            FileType::RegularFile => DirEntryType::RegularFile,
+            FileType::Symlink => DirEntryType::Symlink,
+            FileType::Socket => DirEntryType::Socket,
+        }
+    }
+}
+
+impl TryFrom<DirEntryType> for FileType {
+    type Error = DirEntryType;
+
+    /// Converts a directory entry type to a file type. Fails for [`DirEntryType::Unknown`] and
+    /// [`DirEntryType::Xattr`], which do not correspond with an inode.
+    fn try_from(value: DirEntryType) -> Result<Self, Self::Error> {
+        match value {
+            DirEntryType::Fifo => Ok(FileType::Fifo),
+            DirEntryType::CharDevice => Ok(FileType::CharDevice),
+            DirEntryType::Directory => Ok(FileType::Directory),
+            DirEntryType::BlockDevice => Ok(FileType::BlockDevice),
+            DirEntryType::RegularFile => Ok(FileType::RegularFile),
+            DirEntryType::Symlink => Ok(FileType::Symlink),
+            DirEntryType::Socket => Ok(FileType::Socket),
+            DirEntryType::Unknown | DirEntryType::Xattr => Err(value),
+        }
+    }
+}
+
+bitflags! {
+    /// The permission bits of [`InodeItem::mode`].
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct InodePermissions: u32 {
+        /// Set the user ID on execution.
+        const SET_UID = 0o4000;
+
+        /// Set the group ID on execution. For directories, new entries inherit the group.
+        const SET_GID = 0o2000;
+
+        /// For directories, only the owner of an entry may remove or rename it.
+        const STICKY = 0o1000;
+
+        const OWNER_READ = 0o400;
+        const OWNER_WRITE = 0o200;
+        const OWNER_EXECUTE = 0o100;
+        const GROUP_READ = 0o040;
+        const GROUP_WRITE = 0o020;
+        const GROUP_EXECUTE = 0o010;
+        const OTHER_READ = 0o004;
+        const OTHER_WRITE = 0o002;
+        const OTHER_EXECUTE = 0o001;
+    }
+}
+
 bitflags! {
     pub struct InodeFlags: u64 {
         /// Do not perform checksum operations.
@@ -0,0 +1,155 @@
+use static_assertions::const_assert_eq;
+use zerocopy::{
+    FromBytes as _,
+    little_endian::{U16 as U16LE, U64 as U64LE},
+};
+use zerocopy_derive::*;
+
+/// References a name that links to an inode from its parent directory. This is used by
+/// `INODE_REF` items, where the key's offset is the object ID of the parent directory.
+///
+/// The name is stored immediately after the struct. An item may contain multiple references
+/// back-to-back when an inode has several hard links in the same directory. To walk all of them,
+/// see [`InodeRefIter`].
+#[derive(Copy, Clone, Debug, Hash, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct InodeRef {
+    /// The index of the matching `DIR_INDEX` entry in the parent directory.
+    pub index: U64LE,
+
+    /// The length of the name, stored after this struct.
+    pub name_len: U16LE,
+}
+const_assert_eq!(core::mem::size_of::<InodeRef>(), 10);
+
+/// References a name that links to an inode from its parent directory. This is used by
+/// `INODE_EXTREF` items, where the key's offset is a hash of the parent directory's object ID and
+/// the name.
+///
+/// Extended references are used when the references of an inode no longer fit in a single
+/// `INODE_REF` item. Unlike [`InodeRef`], the parent directory is stored in the struct because
+/// the references in a single item may belong to different parents.
+///
+/// The name is stored immediately after the struct. To walk all of the references in an item,
+/// see [`InodeExtrefIter`].
+#[derive(Copy, Clone, Debug, Hash, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct InodeExtref {
+    /// The object ID of the parent directory.
+    pub parent_objectid: U64LE,
+
+    /// The index of the matching `DIR_INDEX` entry in the parent directory.
+    pub index: U64LE,
+
+    /// The length of the name, stored after this struct.
+    pub name_len: U16LE,
+}
+const_assert_eq!(core::mem::size_of::<InodeExtref>(), 18);
+
+/// A single reference yielded by [`InodeRefIter`].
+#[derive(Copy, Clone, Debug)]
+pub struct InodeRefEntry<'a> {
+    /// The fixed-size part of the reference.
+    pub inode_ref: &'a InodeRef,
+
+    /// The name of the link in the parent directory.
+    pub name: &'a [u8],
+}
+
+/// An iterator over the [`InodeRef`] entries packed into the data of an `INODE_REF` item.
+///
+/// Iteration stops early if the remaining bytes are too short to hold a complete entry. Those
+/// bytes can be retrieved with [`remainder`].
+///
+/// [`remainder`]: InodeRefIter::remainder
+#[derive(Clone, Debug)]
+pub struct InodeRefIter<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> InodeRefIter<'a> {
+    /// Creates an iterator over the references in the data of an `INODE_REF` item.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the bytes that have not been parsed yet. This is empty once iteration has
+    /// completed over well-formed data.
+    pub fn remainder(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> Iterator for InodeRefIter<'a> {
+    type Item = InodeRefEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (inode_ref, rest) = InodeRef::ref_from_prefix(self.bytes).ok()?;
+
+        let name_len = inode_ref.name_len.get() as usize;
+        if rest.len() < name_len {
+            return None;
+        }
+
+        let (name, rest) = rest.split_at(name_len);
+        self.bytes = rest;
+
+        Some(InodeRefEntry { inode_ref, name })
+    }
+}
+
+impl core::iter::FusedIterator for InodeRefIter<'_> {}
+
+/// A single reference yielded by [`InodeExtrefIter`].
+#[derive(Copy, Clone, Debug)]
+pub struct InodeExtrefEntry<'a> {
+    /// The fixed-size part of the reference.
+    pub extref: &'a InodeExtref,
+
+    /// The name of the link in the parent directory.
+    pub name: &'a [u8],
+}
+
+/// An iterator over the [`InodeExtref`] entries packed into the data of an `INODE_EXTREF` item.
+///
+/// Iteration stops early if the remaining bytes are too short to hold a complete entry. Those
+/// bytes can be retrieved with [`remainder`].
+///
+/// [`remainder`]: InodeExtrefIter::remainder
+#[derive(Clone, Debug)]
+pub struct InodeExtrefIter<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> InodeExtrefIter<'a> {
+    /// Creates an iterator over the references in the data of an `INODE_EXTREF` item.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the bytes that have not been parsed yet. This is empty once iteration has
+    /// completed over well-formed data.
+    pub fn remainder(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> Iterator for InodeExtrefIter<'a> {
+    type Item = InodeExtrefEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (extref, rest) = InodeExtref::ref_from_prefix(self.bytes).ok()?;
+
+        let name_len = extref.name_len.get() as usize;
+        if rest.len() < name_len {
+            return None;
+        }
+
+        let (name, rest) = rest.split_at(name_len);
+        self.bytes = rest;
+
+        Some(InodeExtrefEntry { extref, name })
+    }
+}
+
+impl core::iter::FusedIterator for InodeExtrefIter<'_> {}
@@ -1,4 +1,6 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive, TryFromPrimitiveError};
 use static_assertions::const_assert_eq;
+use strum::EnumIter;
 use zerocopy::little_endian::U64 as U64LE;
 use zerocopy_derive::*;
 
@@ -7,7 +9,186 @@ use zerocopy_derive::*;
 #[repr(C, packed)]
 pub struct Key {
     pub objectid: U64LE,
+
+    /// The type of the item, which should correspond with a value from [`KeyType`].
     pub key_type: u8,
+
     pub offset: U64LE,
 }
 const_assert_eq!(core::mem::size_of::<Key>(), 17);
+
+impl Key {
+    /// Creates a new key from its components.
+    pub fn new(objectid: u64, key_type: KeyType, offset: u64) -> Self {
+        Self {
+            objectid: U64LE::new(objectid),
+            key_type: key_type.into(),
+            offset: U64LE::new(offset),
+        }
+    }
+
+    /// Returns the [`key_type`] as a [`KeyType`], if it is a known value.
+    ///
+    /// [`key_type`]: Key::key_type
+    pub fn typed_key_type(&self) -> Result<KeyType, TryFromPrimitiveError<KeyType>> {
+        KeyType::try_from(self.key_type)
+    }
+}
+
+/// The type of an item, as stored in [`Key::key_type`].
+///
+/// The type determines both the meaning of the [`objectid`] and [`offset`] fields of the key and
+/// the structure of the item data, if any.
+///
+/// [`objectid`]: Key::objectid
+/// [`offset`]: Key::offset
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    EnumIter,
+    IntoPrimitive,
+    TryFromPrimitive,
+    IntoBytes,
+    TryFromBytes,
+    Unaligned,
+    KnownLayout,
+    Immutable,
+)]
+#[repr(u8)]
+pub enum KeyType {
+    /// Contains an [`InodeItem`](crate::InodeItem).
+    InodeItem = 1,
+
+    /// Contains one or more inode references. The offset is the object ID of the parent
+    /// directory.
+    InodeRef = 12,
+
+    /// Contains one or more extended inode references. The offset is a hash of the parent
+    /// directory's object ID and the name.
+    InodeExtref = 13,
+
+    /// Contains one or more extended attributes. The offset is a hash of the attribute name.
+    XattrItem = 24,
+
+    /// Contains the fs-verity descriptor of a file.
+    VerityDescItem = 36,
+
+    /// Contains the fs-verity Merkle tree of a file.
+    VerityMerkleItem = 37,
+
+    /// Marks an object as an orphan that should be cleaned up. This item has no data.
+    OrphanItem = 48,
+
+    /// Records the range of directory items logged for a directory in the log tree.
+    DirLogItem = 60,
+
+    /// Records the range of directory indexes logged for a directory in the log tree.
+    DirLogIndex = 72,
+
+    /// Contains one or more directory entries. The offset is a hash of the entry name.
+    DirItem = 84,
+
+    /// Contains a single directory entry. The offset is the index of the entry in the directory.
+    DirIndex = 96,
+
+    /// Contains a file extent item. The offset is the byte offset within the file.
+    ExtentData = 108,
+
+    /// Contains data checksums. The offset is the logical address of the first checksummed byte.
+    ExtentCsum = 128,
+
+    /// Contains a [`RootItem`](crate::RootItem). The object ID is the ID of the tree.
+    RootItem = 132,
+
+    /// Contains a [`RootRef`](crate::RootRef) pointing from a subvolume to its parent.
+    RootBackref = 144,
+
+    /// Contains a [`RootRef`](crate::RootRef) pointing from a subvolume to its child.
+    RootRef = 156,
+
+    /// Contains an extent item. The object ID is the logical address of the extent and the offset
+    /// is its length.
+    ExtentItem = 168,
+
+    /// Contains a skinny extent item for a tree block. The object ID is the logical address of the
+    /// block and the offset is its level.
+    MetadataItem = 169,
+
+    /// Contains the ID of the root that owns an extent, when simple quotas are enabled.
+    ExtentOwnerRef = 172,
+
+    /// An indirect back reference for a tree block.
+    TreeBlockRef = 176,
+
+    /// An indirect back reference for a data extent.
+    ExtentDataRef = 178,
+
+    /// A shared back reference for a tree block.
+    SharedBlockRef = 182,
+
+    /// A shared back reference for a data extent.
+    SharedDataRef = 184,
+
+    /// Contains a [`BlockGroupItem`](crate::BlockGroupItem). The object ID is the logical address
+    /// of the block group and the offset is its length.
+    BlockGroupItem = 192,
+
+    /// Contains the free space information for a block group in the free space tree.
+    FreeSpaceInfo = 198,
+
+    /// Describes a free extent in the free space tree. This item has no data.
+    FreeSpaceExtent = 199,
+
+    /// Contains a bitmap of free sectors in the free space tree.
+    FreeSpaceBitmap = 200,
+
+    /// Contains a [`DevExtent`](crate::DevExtent). The object ID is the device ID and the offset
+    /// is the physical address of the extent on that device.
+    DevExtent = 204,
+
+    /// Contains a [`DevItem`](crate::DevItem). The offset is the device ID.
+    DevItem = 216,
+
+    /// Contains a [`Chunk`](crate::Chunk). The offset is the logical address of the chunk.
+    ChunkItem = 228,
+
+    /// Contains the stripe mapping of an extent in the raid stripe tree.
+    RaidStripe = 230,
+
+    /// Contains the status of quota groups.
+    QgroupStatus = 240,
+
+    /// Contains the usage information of a quota group.
+    QgroupInfo = 242,
+
+    /// Contains the limits of a quota group.
+    QgroupLimit = 244,
+
+    /// Relates two quota groups. This item has no data.
+    QgroupRelation = 246,
+
+    /// An item whose meaning depends on its object ID and which does not persist across
+    /// operations, such as the balance item.
+    TemporaryItem = 248,
+
+    /// An item whose meaning depends on its object ID and which persists, such as device
+    /// statistics.
+    PersistentItem = 249,
+
+    /// Contains the state of a device replace operation.
+    DevReplace = 250,
+
+    /// Maps a subvolume UUID to subvolume IDs in the UUID tree.
+    UuidKeySubvol = 251,
+
+    /// Maps a received subvolume UUID to subvolume IDs in the UUID tree.
+    UuidKeyReceivedSubvol = 252,
+
+    /// Contains an arbitrary string. This is only used for debugging and testing.
+    StringItem = 253,
+}
+const_assert_eq!(core::mem::size_of::<KeyType>(), 1);
@@ -1,7 +1,11 @@
 mod dev_item;
+mod dir_item;
+mod file_extent_item;
 mod inode_item;
+mod inode_ref;
 mod key;
 mod node;
+mod object_id;
 mod root_backup;
 mod root_item;
 mod root_ref;
@@ -9,9 +13,13 @@ mod super_block;
 mod time;
 
 pub use dev_item::*;
+pub use dir_item::*;
+pub use file_extent_item::*;
 pub use inode_item::*;
+pub use inode_ref::*;
 pub use key::*;
 pub use node::*;
+pub use object_id::*;
 pub use root_backup::*;
 pub use root_item::*;
 pub use root_ref::*;
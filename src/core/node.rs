@@ -1,12 +1,23 @@
-use crate::{Key, UuidBytes, constants::CSUM_SIZE};
-use num_enum::{IntoPrimitive, TryFromPrimitive};
+use crate::{Key, ObjectId, TreeId, UuidBytes, constants::CSUM_SIZE};
+use bitflags::bitflags;
+use num_enum::{IntoPrimitive, TryFromPrimitive, TryFromPrimitiveError};
 use static_assertions::const_assert_eq;
 use strum::EnumIter;
 use zerocopy::little_endian::{U32 as U32LE, U64 as U64LE};
 use zerocopy_derive::*;
 
 /// The data stored at the start of every node.
-#[derive(Clone, Debug, IntoBytes, TryFromBytes, Unaligned, KnownLayout)]
+///
+/// On disk, [`flags`] and [`backref_rev`] form a single little-endian 64-bit word, with the
+/// backref revision in the most significant byte. Use [`typed_flags`] and [`typed_backref_rev`]
+/// to read them, or [`raw_flags`] for the whole word.
+///
+/// [`flags`]: Header::flags
+/// [`backref_rev`]: Header::backref_rev
+/// [`typed_flags`]: Header::typed_flags
+/// [`typed_backref_rev`]: Header::typed_backref_rev
+/// [`raw_flags`]: Header::raw_flags
+#[derive(Clone, Debug, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
 #[repr(C, packed)]
 pub struct Header {
     /// The checksum of everything after this field, including the
@@ -19,11 +30,16 @@ pub struct Header {
     /// The logical address of this node.
     pub logical_address: U64LE,
 
-    /// The first 7 bits represent flags.
+    /// The lower 56 bits of the flags word. See [`HeaderFlags`] for values.
     pub flags: [u8; 7],
 
-    /// The backref revision, which maps to a [`BackrefRevision`] value.
-    pub backref_rev: BackrefRevision,
+    /// The backref revision, which should correspond with a value from [`BackrefRevision`].
+    ///
+    /// Before version 0.6.0, this field was a [`BackrefRevision`], which made reading any header
+    /// with an unknown revision fail. Use [`typed_backref_rev`] to get the enum.
+    ///
+    /// [`typed_backref_rev`]: Header::typed_backref_rev
+    pub backref_rev: u8,
 
     /// The chunk tree UUID.
     pub chunk_tree_uuid: UuidBytes,
@@ -42,6 +58,86 @@ pub struct Header {
 }
 const_assert_eq!(core::mem::size_of::<Header>(), 101);
 
+impl Header {
+    const BACKREF_REV_MASK: u64 = 0xff << 56;
+
+    /// Returns the [`tree_id`] as a [`TreeId`]. If the value does not identify a tree, it is
+    /// returned as an [`ObjectId`] instead.
+    ///
+    /// [`tree_id`]: Header::tree_id
+    pub fn typed_tree_id(&self) -> Result<TreeId, ObjectId> {
+        TreeId::try_from(self.tree_id.get())
+    }
+
+    /// Returns the whole flags word, combining [`flags`] and [`backref_rev`].
+    ///
+    /// [`flags`]: Header::flags
+    /// [`backref_rev`]: Header::backref_rev
+    pub fn raw_flags(&self) -> u64 {
+        let mut bytes = [0; 8];
+        bytes[..7].copy_from_slice(&self.flags);
+        bytes[7] = self.backref_rev;
+
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Sets the whole flags word, splitting it across [`flags`] and [`backref_rev`].
+    ///
+    /// [`flags`]: Header::flags
+    /// [`backref_rev`]: Header::backref_rev
+    pub fn set_raw_flags(&mut self, raw: u64) {
+        let bytes = raw.to_le_bytes();
+        self.flags.copy_from_slice(&bytes[..7]);
+        self.backref_rev = bytes[7];
+    }
+
+    /// Returns the [`flags`] as [`HeaderFlags`]. Unknown bits are retained.
+    ///
+    /// [`flags`]: Header::flags
+    pub fn typed_flags(&self) -> HeaderFlags {
+        HeaderFlags::from_bits_retain(self.raw_flags() & !Self::BACKREF_REV_MASK)
+    }
+
+    /// Sets the [`flags`], leaving [`backref_rev`] unchanged. Bits that do not fit in the lower
+    /// 56 bits are discarded.
+    ///
+    /// [`flags`]: Header::flags
+    /// [`backref_rev`]: Header::backref_rev
+    pub fn set_typed_flags(&mut self, flags: HeaderFlags) {
+        let raw =
+            (self.raw_flags() & Self::BACKREF_REV_MASK) | (flags.bits() & !Self::BACKREF_REV_MASK);
+        self.set_raw_flags(raw);
+    }
+
+    /// Returns the [`backref_rev`] as a [`BackrefRevision`], if it is a known value.
+    ///
+    /// [`backref_rev`]: Header::backref_rev
+    pub fn typed_backref_rev(
+        &self,
+    ) -> Result<BackrefRevision, TryFromPrimitiveError<BackrefRevision>> {
+        BackrefRevision::try_from(self.backref_rev)
+    }
+
+    /// Sets the [`backref_rev`].
+    ///
+    /// [`backref_rev`]: Header::backref_rev
+    pub fn set_backref_rev(&mut self, backref_rev: BackrefRevision) {
+        self.backref_rev = backref_rev.into();
+    }
+}
+
+bitflags! {
+    /// The flags of a [`Header`], stored in the lower 56 bits of the flags word.
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct HeaderFlags: u64 {
+        /// The node has been written to disk in the current transaction.
+        const WRITTEN = 1 << 0;
+
+        /// The node belongs to a tree being relocated.
+        const RELOC = 1 << 1;
+    }
+}
+
 /// For internal (non-leaf) nodes, the [node header] is followed by a dynamic amount of key
 /// pointers.
 ///
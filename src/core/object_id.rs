@@ -0,0 +1,222 @@
+/// An object ID, as stored in [`Key::objectid`] or [`Header::tree_id`].
+///
+/// Some object IDs are reserved for well-known trees and items. Several of these are negative
+/// numbers stored as their two's complement `u64` representation. Object IDs within
+/// [`FIRST_FREE`] and [`LAST_FREE`] are free to be used for inodes and subvolumes.
+///
+/// [`Key::objectid`]: crate::Key::objectid
+/// [`Header::tree_id`]: crate::Header::tree_id
+/// [`FIRST_FREE`]: ObjectId::FIRST_FREE
+/// [`LAST_FREE`]: ObjectId::LAST_FREE
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ObjectId(pub u64);
+
+impl ObjectId {
+    /// The object ID of the device statistics items. This shares its value with the device
+    /// replace item.
+    pub const DEV_STATS: Self = Self(0);
+
+    /// The object ID of the device replace item.
+    pub const DEV_REPLACE: Self = Self(0);
+
+    /// The object ID of the root tree.
+    pub const ROOT_TREE: Self = Self(1);
+
+    /// The object ID of the extent tree.
+    pub const EXTENT_TREE: Self = Self(2);
+
+    /// The object ID of the chunk tree.
+    pub const CHUNK_TREE: Self = Self(3);
+
+    /// The object ID of the device tree.
+    pub const DEV_TREE: Self = Self(4);
+
+    /// The object ID of the top-level filesystem tree.
+    pub const FS_TREE: Self = Self(5);
+
+    /// The object ID of the directory inode within the root tree that holds the default
+    /// subvolume.
+    pub const ROOT_TREE_DIR: Self = Self(6);
+
+    /// The object ID of the checksum tree.
+    pub const CSUM_TREE: Self = Self(7);
+
+    /// The object ID of the quota tree.
+    pub const QUOTA_TREE: Self = Self(8);
+
+    /// The object ID of the UUID tree.
+    pub const UUID_TREE: Self = Self(9);
+
+    /// The object ID of the free space tree.
+    pub const FREE_SPACE_TREE: Self = Self(10);
+
+    /// The object ID of the block group tree.
+    pub const BLOCK_GROUP_TREE: Self = Self(11);
+
+    /// The object ID of the raid stripe tree.
+    pub const RAID_STRIPE_TREE: Self = Self(12);
+
+    /// The object ID of the items in the device tree that hold [`DevItem`]s.
+    ///
+    /// [`DevItem`]: crate::DevItem
+    pub const DEV_ITEMS: Self = Self(1);
+
+    /// The object ID of the balance item.
+    pub const BALANCE: Self = Self(-4i64 as u64);
+
+    /// The object ID of orphan items for deleted roots.
+    pub const ORPHAN: Self = Self(-5i64 as u64);
+
+    /// The object ID of log trees.
+    pub const TREE_LOG: Self = Self(-6i64 as u64);
+
+    /// The object ID used to fix up the log tree during replay.
+    pub const TREE_LOG_FIXUP: Self = Self(-7i64 as u64);
+
+    /// The object ID of relocation trees.
+    pub const TREE_RELOC: Self = Self(-8i64 as u64);
+
+    /// The object ID of the data relocation tree.
+    pub const DATA_RELOC_TREE: Self = Self(-9i64 as u64);
+
+    /// The object ID of the checksum items in the checksum tree.
+    pub const EXTENT_CSUM: Self = Self(-10i64 as u64);
+
+    /// The object ID of the free space cache headers in the root tree.
+    pub const FREE_SPACE: Self = Self(-11i64 as u64);
+
+    /// The object ID of the free inode number cache headers.
+    pub const FREE_INO: Self = Self(-12i64 as u64);
+
+    /// Used to mark items that are shared by multiple objects, such as in the log tree.
+    pub const MULTIPLE: Self = Self(-255i64 as u64);
+
+    /// The first object ID available for inodes and subvolumes.
+    pub const FIRST_FREE: Self = Self(256);
+
+    /// The last object ID available for inodes and subvolumes.
+    pub const LAST_FREE: Self = Self(-256i64 as u64);
+
+    /// The object ID of the first chunk item in the chunk tree.
+    pub const FIRST_CHUNK_TREE: Self = Self(256);
+
+    /// The object ID of the btree inode.
+    pub const BTREE_INODE: Self = Self(1);
+
+    /// The object ID of the empty directory that replaces a subvolume when it is not present.
+    pub const EMPTY_SUBVOL_DIR: Self = Self(2);
+
+    /// Returns whether this object ID lies within the range available for inodes and subvolumes.
+    pub fn is_free_range(self) -> bool {
+        self >= Self::FIRST_FREE && self <= Self::LAST_FREE
+    }
+}
+
+impl From<u64> for ObjectId {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ObjectId> for u64 {
+    fn from(value: ObjectId) -> Self {
+        value.0
+    }
+}
+
+/// The ID of a tree, as stored in [`Header::tree_id`] and as the object ID of a [`RootItem`].
+///
+/// [`Header::tree_id`]: crate::Header::tree_id
+/// [`RootItem`]: crate::RootItem
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum TreeId {
+    Root,
+    Extent,
+    Chunk,
+    Dev,
+    /// The top-level filesystem tree, which is also subvolume 5.
+    Fs,
+    Csum,
+    Quota,
+    Uuid,
+    FreeSpace,
+    BlockGroup,
+    RaidStripe,
+    /// A log tree.
+    TreeLog,
+    /// The log tree fixup tree.
+    TreeLogFixup,
+    /// A relocation tree.
+    TreeReloc,
+    /// The data relocation tree.
+    DataReloc,
+    /// A subvolume or snapshot with the given object ID, which is within the range of
+    /// [`ObjectId::FIRST_FREE`] and [`ObjectId::LAST_FREE`].
+    Subvolume(u64),
+}
+
+impl TreeId {
+    /// Returns the object ID of the tree.
+    pub fn objectid(self) -> ObjectId {
+        match self {
+            TreeId::Root => ObjectId::ROOT_TREE,
+            TreeId::Extent => ObjectId::EXTENT_TREE,
+            TreeId::Chunk => ObjectId::CHUNK_TREE,
+            TreeId::Dev => ObjectId::DEV_TREE,
+            TreeId::Fs => ObjectId::FS_TREE,
+            TreeId::Csum => ObjectId::CSUM_TREE,
+            TreeId::Quota => ObjectId::QUOTA_TREE,
+            TreeId::Uuid => ObjectId::UUID_TREE,
+            TreeId::FreeSpace => ObjectId::FREE_SPACE_TREE,
+            TreeId::BlockGroup => ObjectId::BLOCK_GROUP_TREE,
+            TreeId::RaidStripe => ObjectId::RAID_STRIPE_TREE,
+            TreeId::TreeLog => ObjectId::TREE_LOG,
+            TreeId::TreeLogFixup => ObjectId::TREE_LOG_FIXUP,
+            TreeId::TreeReloc => ObjectId::TREE_RELOC,
+            TreeId::DataReloc => ObjectId::DATA_RELOC_TREE,
+            TreeId::Subvolume(id) => ObjectId(id),
+        }
+    }
+
+    /// Returns whether the tree is a filesystem tree, which contains inodes and file data. This
+    /// includes the top-level filesystem tree, subvolumes, and the data relocation tree.
+    pub fn is_fs_tree(self) -> bool {
+        matches!(self, TreeId::Fs | TreeId::Subvolume(_) | TreeId::DataReloc)
+    }
+}
+
+impl TryFrom<u64> for TreeId {
+    type Error = ObjectId;
+
+    /// Converts an object ID to a tree ID. Returns the object ID as the error if it does not
+    /// identify a tree.
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        let id = ObjectId(value);
+
+        Ok(match id {
+            ObjectId::ROOT_TREE => TreeId::Root,
+            ObjectId::EXTENT_TREE => TreeId::Extent,
+            ObjectId::CHUNK_TREE => TreeId::Chunk,
+            ObjectId::DEV_TREE => TreeId::Dev,
+            ObjectId::FS_TREE => TreeId::Fs,
+            ObjectId::CSUM_TREE => TreeId::Csum,
+            ObjectId::QUOTA_TREE => TreeId::Quota,
+            ObjectId::UUID_TREE => TreeId::Uuid,
+            ObjectId::FREE_SPACE_TREE => TreeId::FreeSpace,
+            ObjectId::BLOCK_GROUP_TREE => TreeId::BlockGroup,
+            ObjectId::RAID_STRIPE_TREE => TreeId::RaidStripe,
+            ObjectId::TREE_LOG => TreeId::TreeLog,
+            ObjectId::TREE_LOG_FIXUP => TreeId::TreeLogFixup,
+            ObjectId::TREE_RELOC => TreeId::TreeReloc,
+            ObjectId::DATA_RELOC_TREE => TreeId::DataReloc,
+            id if id.is_free_range() => TreeId::Subvolume(value),
+            id => return Err(id),
+        })
+    }
+}
+
+impl From<TreeId> for u64 {
+    fn from(value: TreeId) -> Self {
+        value.objectid().0
+    }
+}
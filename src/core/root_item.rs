@@ -1,4 +1,5 @@
-use crate::{InodeItem, Key, Time, UuidBytes};
+use crate::{InodeItem, Key, Time, Uuid, UuidBytes};
+use bitflags::bitflags;
 use static_assertions::const_assert_eq;
 use zerocopy::little_endian::{U32 as U32LE, U64 as U64LE};
 use zerocopy_derive::*;
@@ -24,6 +25,7 @@ pub struct RootItem {
     /// The transaction ID of the last transaction that created a snapshot of this root.
     pub last_snapshot: U64LE,
 
+    /// Flags for the root. See [`RootFlags`] for values.
     pub flags: U64LE,
 
     /// Only 0 or 1. Historically contained a reference count.
@@ -96,3 +98,70 @@ pub struct RootItem {
     pub _unused: [u64; 8],
 }
 const_assert_eq!(core::mem::size_of::<RootItem>(), 439);
+
+impl RootItem {
+    /// Returns the [`flags`] as [`RootFlags`]. Unknown bits are retained.
+    ///
+    /// [`flags`]: RootItem::flags
+    pub fn typed_flags(&self) -> RootFlags {
+        RootFlags::from_bits_retain(self.flags.get())
+    }
+
+    /// Returns whether the subvolume is read-only.
+    pub fn is_readonly(&self) -> bool {
+        self.typed_flags().contains(RootFlags::SUBVOL_RDONLY)
+    }
+
+    /// Returns whether the fields starting at [`generation_v2`] are valid. If not, the root was
+    /// last modified by an implementation that did not know about them, and they should be
+    /// ignored.
+    ///
+    /// [`generation_v2`]: RootItem::generation_v2
+    pub fn v2_fields_valid(&self) -> bool {
+        self.generation_v2.get() == self.generation.get()
+    }
+
+    /// Returns whether the subvolume was created by receiving a send stream. This is the case if
+    /// [`stransid`] is non-zero or [`received_uuid`] is not nil.
+    ///
+    /// Always returns `false` if the [v2 fields] are not valid.
+    ///
+    /// [`stransid`]: RootItem::stransid
+    /// [`received_uuid`]: RootItem::received_uuid
+    /// [v2 fields]: RootItem::v2_fields_valid
+    pub fn is_received(&self) -> bool {
+        self.v2_fields_valid() && (self.stransid.get() != 0 || !Uuid(self.received_uuid).is_nil())
+    }
+
+    /// Returns whether the subvolume is a snapshot of another subvolume, which is the case if
+    /// [`parent_uuid`] is not nil.
+    ///
+    /// Always returns `false` if the [v2 fields] are not valid.
+    ///
+    /// [`parent_uuid`]: RootItem::parent_uuid
+    /// [v2 fields]: RootItem::v2_fields_valid
+    pub fn is_snapshot(&self) -> bool {
+        self.v2_fields_valid() && !Uuid(self.parent_uuid).is_nil()
+    }
+
+    /// Returns whether the tree is partway through being dropped, which is the case if
+    /// [`drop_progress`] or [`drop_level`] is set.
+    ///
+    /// [`drop_progress`]: RootItem::drop_progress
+    /// [`drop_level`]: RootItem::drop_level
+    pub fn is_being_dropped(&self) -> bool {
+        self.drop_progress.objectid.get() != 0 || self.drop_level != 0
+    }
+}
+
+bitflags! {
+    /// The flags of a [`RootItem`].
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct RootFlags: u64 {
+        /// The subvolume is read-only.
+        const SUBVOL_RDONLY = 1 << 0;
+
+        /// The subvolume has been deleted and is waiting to be cleaned up.
+        const SUBVOL_DEAD = 1 << 48;
+    }
+}
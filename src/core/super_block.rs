@@ -2,6 +2,7 @@ use crate::{
     DevItem, RootBackup,
     constants::{CSUM_SIZE, FSID_SIZE, LABEL_SIZE, MAX_SYSTEM_CHUNK_ARRAY_SIZE, NUM_BACKUP_ROOTS},
 };
+use bitflags::bitflags;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use static_assertions::const_assert_eq;
 use strum::EnumIter;
@@ -36,7 +37,7 @@ pub struct SuperBlock {
     /// The physical address of this block.
     pub bytenr: U64LE,
 
-    /// Flags
+    /// Flags for the superblock. See [`SuperBlockFlags`] for values.
     pub flags: U64LE,
 
     /// The magic must be equal to `"_BHRfS_M"` in ASCII.
@@ -63,7 +64,9 @@ pub struct SuperBlock {
 
     pub bytes_used: U64LE,
 
-    /// The root directory's object ID, which is typically 6.
+    /// The root directory's object ID, which is typically [`ObjectId::ROOT_TREE_DIR`].
+    ///
+    /// [`ObjectId::ROOT_TREE_DIR`]: crate::ObjectId::ROOT_TREE_DIR
     pub root_dir_objectid: U64LE,
 
     /// The number of devices the current filesystem spans.
@@ -86,12 +89,16 @@ pub struct SuperBlock {
 
     pub chunk_root_generation: U64LE,
 
+    /// Features that any implementation can use, even without supporting them. See
+    /// [`CompatFlags`] for values.
     pub compat_flags: U64LE,
 
-    /// Only implementations that support these flags can write to the filesystem.
+    /// Only implementations that support these flags can write to the filesystem. See
+    /// [`CompatRoFlags`] for values.
     pub compat_ro_flags: U64LE,
 
-    /// Only implementations that support these flags can use the filesystem.
+    /// Only implementations that support these flags can use the filesystem. See
+    /// [`IncompatFlags`] for values.
     pub incompat_flags: U64LE,
 
     /// The checksum type.
@@ -110,14 +117,43 @@ pub struct SuperBlock {
     pub dev_item: DevItem,
 
     /// The label represented as a null-terminated UTF-8 string. May not contain `'/'` or `'\\'`.
+    ///
+    /// See [`label_str`] and [`set_label`] to access it as a string.
+    ///
+    /// [`label_str`]: SuperBlock::label_str
+    /// [`set_label`]: SuperBlock::set_label
     pub label: [u8; LABEL_SIZE],
 
     pub cache_generation: U64LE,
 
     pub uuid_tree_generation: U64LE,
 
+    /// The UUID stamped into tree blocks when [`IncompatFlags::METADATA_UUID`] is set. Otherwise,
+    /// this is unused and tree blocks use [`fsid`]. See [`effective_metadata_uuid`].
+    ///
+    /// [`fsid`]: SuperBlock::fsid
+    /// [`effective_metadata_uuid`]: SuperBlock::effective_metadata_uuid
+    pub metadata_uuid: [u8; FSID_SIZE],
+
+    /// The number of global roots, such as extent and checksum trees. This is only used with
+    /// [`IncompatFlags::EXTENT_TREE_V2`].
+    pub nr_global_roots: U64LE,
+
+    /// The logical address of the block group tree's root. This is only used with
+    /// [`CompatRoFlags::BLOCK_GROUP_TREE`].
+    pub block_group_root: U64LE,
+
+    /// The generation of the block group tree's root.
+    pub block_group_root_generation: U64LE,
+
+    /// The level of the block group tree's root.
+    pub block_group_root_level: u8,
+
+    /// Reserved for extensibility.
+    pub _reserved8: [u8; 7],
+
     /// Reserved for extensibility.
-    pub _reserved: [U64LE; 30],
+    pub _reserved: [U64LE; 24],
 
     pub sys_chunk_array: [u8; MAX_SYSTEM_CHUNK_ARRAY_SIZE],
 
@@ -127,6 +163,252 @@ pub struct SuperBlock {
 }
 const_assert_eq!(core::mem::size_of::<SuperBlock>(), 4096);
 
+impl SuperBlock {
+    /// Returns the [`flags`] as [`SuperBlockFlags`]. Unknown bits are retained.
+    ///
+    /// [`flags`]: SuperBlock::flags
+    pub fn typed_flags(&self) -> SuperBlockFlags {
+        SuperBlockFlags::from_bits_retain(self.flags.get())
+    }
+
+    /// Returns the [`compat_flags`] as [`CompatFlags`]. Unknown bits are retained.
+    ///
+    /// These bits can safely be ignored. Bits unknown to this crate can be found with
+    /// [`CompatFlags::unknown_bits`].
+    ///
+    /// [`compat_flags`]: SuperBlock::compat_flags
+    pub fn typed_compat_flags(&self) -> CompatFlags {
+        CompatFlags::from_bits_retain(self.compat_flags.get())
+    }
+
+    /// Returns the [`compat_ro_flags`] as [`CompatRoFlags`]. Unknown bits are retained.
+    ///
+    /// An implementation should refuse to write to the filesystem if any bits it does not
+    /// support are set. Bits unknown to this crate can be found with
+    /// [`CompatRoFlags::unknown_bits`].
+    ///
+    /// [`compat_ro_flags`]: SuperBlock::compat_ro_flags
+    pub fn typed_compat_ro_flags(&self) -> CompatRoFlags {
+        CompatRoFlags::from_bits_retain(self.compat_ro_flags.get())
+    }
+
+    /// Returns the [`incompat_flags`] as [`IncompatFlags`]. Unknown bits are retained.
+    ///
+    /// An implementation should refuse to use the filesystem if any bits it does not support
+    /// are set. Bits unknown to this crate can be found with [`IncompatFlags::unknown_bits`].
+    ///
+    /// [`incompat_flags`]: SuperBlock::incompat_flags
+    pub fn typed_incompat_flags(&self) -> IncompatFlags {
+        IncompatFlags::from_bits_retain(self.incompat_flags.get())
+    }
+
+    /// Returns the UUID that tree blocks are stamped with in [`Header::fs_uuid`].
+    ///
+    /// This is [`metadata_uuid`] if [`IncompatFlags::METADATA_UUID`] is set, which happens when
+    /// the filesystem UUID is changed without rewriting every tree block. Otherwise, it is
+    /// [`fsid`].
+    ///
+    /// [`Header::fs_uuid`]: crate::Header::fs_uuid
+    /// [`metadata_uuid`]: SuperBlock::metadata_uuid
+    /// [`fsid`]: SuperBlock::fsid
+    pub fn effective_metadata_uuid(&self) -> &[u8; FSID_SIZE] {
+        if self.typed_incompat_flags().contains(IncompatFlags::METADATA_UUID) {
+            &self.metadata_uuid
+        } else {
+            &self.fsid
+        }
+    }
+
+    /// Returns the [`label`] up to its NUL terminator. Returns an error if that part of the label
+    /// is not valid UTF-8.
+    ///
+    /// [`label`]: SuperBlock::label
+    pub fn label_str(&self) -> Result<&str, core::str::Utf8Error> {
+        let len = self.label.iter().position(|&c| c == 0).unwrap_or(LABEL_SIZE);
+        core::str::from_utf8(&self.label[..len])
+    }
+
+    /// Sets the [`label`], padding the rest of the field with NUL bytes.
+    ///
+    /// The label must leave room for the NUL terminator, so it may be at most
+    /// `LABEL_SIZE - 1` bytes. It may not contain `'/'`, `'\\'` or NUL characters.
+    ///
+    /// [`label`]: SuperBlock::label
+    pub fn set_label(&mut self, label: &str) -> Result<(), LabelError> {
+        if label.len() >= LABEL_SIZE {
+            return Err(LabelError::TooLong { len: label.len() });
+        }
+
+        if let Some(c) = label.chars().find(|c| matches!(c, '/' | '\\' | '\0')) {
+            return Err(LabelError::InvalidCharacter(c));
+        }
+
+        self.label = [0; LABEL_SIZE];
+        self.label[..label.len()].copy_from_slice(label.as_bytes());
+
+        Ok(())
+    }
+}
+
+/// The reason a label could not be set with [`SuperBlock::set_label`].
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum LabelError {
+    /// The label does not leave room for the NUL terminator in [`SuperBlock::label`].
+    TooLong { len: usize },
+
+    /// The label contains a character that is not allowed.
+    InvalidCharacter(char),
+}
+
+impl core::fmt::Display for LabelError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LabelError::TooLong { len } => {
+                write!(f, "label is {len} bytes long, but at most {} are allowed", LABEL_SIZE - 1)
+            }
+            LabelError::InvalidCharacter(c) => write!(f, "label contains invalid character {c:?}"),
+        }
+    }
+}
+
+impl core::error::Error for LabelError {}
+
+bitflags! {
+    /// The flags of a [`SuperBlock`].
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct SuperBlockFlags: u64 {
+        /// The superblock has been written to disk.
+        const WRITTEN = 1 << 0;
+
+        /// The superblock belongs to a tree relocation.
+        const RELOC = 1 << 1;
+
+        /// The filesystem encountered an error and was forced read-only.
+        const ERROR = 1 << 2;
+
+        /// The filesystem is a seed device, which is read-only and can be sprouted.
+        const SEEDING = 1 << 32;
+
+        /// The filesystem is an image created by `btrfs-image` with data removed.
+        const METADUMP = 1 << 33;
+
+        /// The filesystem is an image created by `btrfs-image` with multiple devices restored.
+        const METADUMP_V2 = 1 << 34;
+
+        /// The filesystem UUID is being changed. This is set while `btrfstune` rewrites every tree
+        /// block.
+        const CHANGING_FSID = 1 << 35;
+
+        /// The metadata UUID is being changed.
+        const CHANGING_FSID_V2 = 1 << 36;
+    }
+}
+
+bitflags! {
+    /// Features that any implementation can use, even without supporting them. See
+    /// [`SuperBlock::compat_flags`].
+    ///
+    /// No such features are currently defined.
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct CompatFlags: u64 {}
+}
+
+impl CompatFlags {
+    /// Returns the bits that do not correspond to a flag known to this crate.
+    pub fn unknown_bits(&self) -> u64 {
+        self.bits() & !Self::all().bits()
+    }
+}
+
+bitflags! {
+    /// Features that only implementations which support them can write to. See
+    /// [`SuperBlock::compat_ro_flags`].
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct CompatRoFlags: u64 {
+        /// The free space tree is used to track free space.
+        const FREE_SPACE_TREE = 1 << 0;
+
+        /// The free space tree is consistent with the extent tree.
+        const FREE_SPACE_TREE_VALID = 1 << 1;
+
+        /// Files may have fs-verity enabled.
+        const VERITY = 1 << 2;
+
+        /// Block group items are stored in the block group tree instead of the extent tree.
+        const BLOCK_GROUP_TREE = 1 << 3;
+    }
+}
+
+impl CompatRoFlags {
+    /// Returns the bits that do not correspond to a flag known to this crate.
+    pub fn unknown_bits(&self) -> u64 {
+        self.bits() & !Self::all().bits()
+    }
+}
+
+bitflags! {
+    /// Features that only implementations which support them can use. See
+    /// [`SuperBlock::incompat_flags`].
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct IncompatFlags: u64 {
+        /// Back references use the current format. This is set on all filesystems created by
+        /// modern tools.
+        const MIXED_BACKREF = 1 << 0;
+
+        /// A default subvolume other than the top-level one has been set.
+        const DEFAULT_SUBVOL = 1 << 1;
+
+        /// Data and metadata may share block groups.
+        const MIXED_GROUPS = 1 << 2;
+
+        /// Extents may be compressed with LZO.
+        const COMPRESS_LZO = 1 << 3;
+
+        /// Extents may be compressed with Zstandard. This was originally reserved for LZ4.
+        const COMPRESS_ZSTD = 1 << 4;
+
+        /// The node size may be larger than the page size.
+        const BIG_METADATA = 1 << 5;
+
+        /// Inodes may have `INODE_EXTREF` items.
+        const EXTENDED_IREF = 1 << 6;
+
+        /// Block groups may use RAID-5 or RAID-6.
+        const RAID56 = 1 << 7;
+
+        /// Tree blocks are recorded with `METADATA_ITEM` items instead of `EXTENT_ITEM` items.
+        const SKINNY_METADATA = 1 << 8;
+
+        /// Holes in files are not recorded with explicit file extent items.
+        const NO_HOLES = 1 << 9;
+
+        /// Tree blocks use [`SuperBlock::metadata_uuid`] instead of the filesystem UUID.
+        const METADATA_UUID = 1 << 10;
+
+        /// Block groups may use RAID1C3 or RAID1C4.
+        const RAID1C34 = 1 << 11;
+
+        /// The filesystem is on zoned devices.
+        const ZONED = 1 << 12;
+
+        /// The filesystem uses version 2 of the extent tree.
+        const EXTENT_TREE_V2 = 1 << 13;
+
+        /// Stripes of data are tracked in the RAID stripe tree.
+        const RAID_STRIPE_TREE = 1 << 14;
+
+        /// Quotas are tracked with simple quotas, which account extents to their owner.
+        const SIMPLE_QUOTA = 1 << 16;
+    }
+}
+
+impl IncompatFlags {
+    /// Returns the bits that do not correspond to a flag known to this crate.
+    pub fn unknown_bits(&self) -> u64 {
+        self.bits() & !Self::all().bits()
+    }
+}
+
 /// The hashing algorithm used for checksumming.
 #[derive(
     Copy,
@@ -149,3 +431,20 @@ pub enum ChecksumType {
     BLAKE2b = 3u16.to_le(),
 }
 const_assert_eq!(core::mem::size_of::<ChecksumType>(), 2);
+
+impl ChecksumType {
+    /// Returns the size of a checksum of this type, in bytes.
+    ///
+    /// Checksums stored in fixed-size fields, such as [`Header::csum`], are padded with zeroes to
+    /// [`CSUM_SIZE`]. Checksums in the checksum tree are stored without padding.
+    ///
+    /// [`Header::csum`]: crate::Header::csum
+    pub fn digest_size(&self) -> usize {
+        match self {
+            ChecksumType::CRC32C => 4,
+            ChecksumType::XXHASH64 => 8,
+            ChecksumType::SHA256 => 32,
+            ChecksumType::BLAKE2b => 32,
+        }
+    }
+}
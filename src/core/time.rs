@@ -1,3 +1,4 @@
+use core::{fmt, time::Duration};
 use static_assertions::const_assert_eq;
 use zerocopy::little_endian::{I64 as I64LE, U32 as U32LE};
 use zerocopy_derive::*;
@@ -28,3 +29,156 @@ pub struct Time {
     pub nanoseconds: U32LE,
 }
 const_assert_eq!(core::mem::size_of::<Time>(), 12);
+
+impl Time {
+    const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+    /// Creates a timestamp from seconds and nanoseconds relative to the Unix epoch. Returns
+    /// `None` if `nanoseconds` is not less than one second.
+    ///
+    /// For times before the epoch, `timestamp` is negative and `nanoseconds` counts forwards from
+    /// it, so -0.25 seconds is a `timestamp` of -1 and `nanoseconds` of 750,000,000.
+    pub fn new(timestamp: i64, nanoseconds: u32) -> Option<Self> {
+        if nanoseconds >= Self::NANOS_PER_SEC {
+            return None;
+        }
+
+        Some(Self { timestamp: timestamp.into(), nanoseconds: nanoseconds.into() })
+    }
+
+    /// Returns whether [`nanoseconds`] is less than one second.
+    ///
+    /// [`nanoseconds`]: Time::nanoseconds
+    pub fn is_valid(&self) -> bool {
+        self.nanoseconds.get() < Self::NANOS_PER_SEC
+    }
+
+    /// Creates a timestamp that is `duration` after the Unix epoch. Returns `None` if it cannot
+    /// be represented.
+    pub fn from_duration_since_epoch(duration: Duration) -> Option<Self> {
+        Self::from_total_nanos(duration.as_nanos().try_into().ok()?)
+    }
+
+    /// Creates a timestamp that is `duration` before the Unix epoch. Returns `None` if it cannot
+    /// be represented.
+    pub fn from_duration_before_epoch(duration: Duration) -> Option<Self> {
+        Self::from_total_nanos(-i128::try_from(duration.as_nanos()).ok()?)
+    }
+
+    /// Returns the time relative to the Unix epoch. This is `Ok` with the time since the epoch,
+    /// or `Err` with the time before the epoch, in the style of [`SystemTime::duration_since`].
+    ///
+    /// If [`nanoseconds`] is not [valid], the excess is carried into the seconds.
+    ///
+    /// [`SystemTime::duration_since`]: https://doc.rust-lang.org/std/time/struct.SystemTime.html#method.duration_since
+    /// [`nanoseconds`]: Time::nanoseconds
+    /// [valid]: Time::is_valid
+    pub fn duration_since_epoch(&self) -> Result<Duration, Duration> {
+        let total = self.total_nanos();
+        let duration = Duration::new(
+            (total.unsigned_abs() / Self::NANOS_PER_SEC as u128) as u64,
+            (total.unsigned_abs() % Self::NANOS_PER_SEC as u128) as u32,
+        );
+
+        if total >= 0 { Ok(duration) } else { Err(duration) }
+    }
+
+    /// Converts the timestamp to a [`SystemTime`]. Returns `None` if it cannot be represented.
+    ///
+    /// [`SystemTime`]: std::time::SystemTime
+    #[cfg(feature = "std")]
+    pub fn to_system_time(&self) -> Option<std::time::SystemTime> {
+        match self.duration_since_epoch() {
+            Ok(after) => std::time::UNIX_EPOCH.checked_add(after),
+            Err(before) => std::time::UNIX_EPOCH.checked_sub(before),
+        }
+    }
+
+    /// Creates a timestamp from a [`SystemTime`]. Returns `None` if it cannot be represented.
+    ///
+    /// [`SystemTime`]: std::time::SystemTime
+    #[cfg(feature = "std")]
+    pub fn from_system_time(time: std::time::SystemTime) -> Option<Self> {
+        match time.duration_since(std::time::UNIX_EPOCH) {
+            Ok(after) => Self::from_duration_since_epoch(after),
+            Err(before) => Self::from_duration_before_epoch(before.duration()),
+        }
+    }
+
+    /// Returns a wrapper that formats the timestamp as an RFC 3339 date and time in UTC, such as
+    /// `2024-02-29T13:45:00.123456789Z`. The fractional seconds are omitted when zero.
+    ///
+    /// Years outside of 0 to 9999 cannot be represented in RFC 3339, so they are formatted with
+    /// a sign and at least 5 digits, as in ISO 8601's expanded representation.
+    pub fn rfc3339(&self) -> Rfc3339 {
+        Rfc3339(*self)
+    }
+
+    fn total_nanos(&self) -> i128 {
+        self.timestamp.get() as i128 * Self::NANOS_PER_SEC as i128 + self.nanoseconds.get() as i128
+    }
+
+    fn from_total_nanos(total: i128) -> Option<Self> {
+        let timestamp = total.div_euclid(Self::NANOS_PER_SEC as i128).try_into().ok()?;
+        let nanoseconds = total.rem_euclid(Self::NANOS_PER_SEC as i128) as u32;
+
+        Self::new(timestamp, nanoseconds)
+    }
+}
+
+/// Formats a [`Time`] as an RFC 3339 date and time. See [`Time::rfc3339`].
+#[derive(Copy, Clone, Debug)]
+pub struct Rfc3339(Time);
+
+impl fmt::Display for Rfc3339 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const SECS_PER_DAY: i128 = 86_400;
+
+        let total = self.0.total_nanos();
+        let secs = total.div_euclid(Time::NANOS_PER_SEC as i128);
+        let nanos = total.rem_euclid(Time::NANOS_PER_SEC as i128);
+
+        let (year, month, day) = civil_from_days(secs.div_euclid(SECS_PER_DAY) as i64);
+        let secs_of_day = secs.rem_euclid(SECS_PER_DAY);
+
+        if (0..=9999).contains(&year) {
+            write!(f, "{year:04}")?;
+        } else {
+            write!(f, "{year:+06}")?;
+        }
+
+        write!(
+            f,
+            "-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+            secs_of_day / 3600,
+            secs_of_day / 60 % 60,
+            secs_of_day % 60,
+        )?;
+
+        if nanos != 0 {
+            write!(f, ".{nanos:09}")?;
+        }
+
+        f.write_str("Z")
+    }
+}
+
+/// Converts a number of days since the Unix epoch to a proleptic Gregorian year, month and day.
+///
+/// This is Howard Hinnant's `civil_from_days` algorithm, which counts in 400-year eras starting
+/// on March 1st so that leap days fall at the end of each year.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u32;
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+    (year, month, day)
+}
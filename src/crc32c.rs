@@ -0,0 +1,35 @@
+//! A table-driven implementation of CRC-32C (Castagnoli), which btrfs uses both for checksums and
+//! for hashing names and back references into key offsets.
+
+const POLYNOMIAL: u32 = 0x82F63B78;
+
+const TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ POLYNOMIAL } else { crc >> 1 };
+            bit += 1;
+        }
+
+        table[i] = crc;
+        i += 1;
+    }
+
+    table
+};
+
+/// Updates `crc` with `data`, without any inversion of the input or output.
+///
+/// The standard CRC-32C of some data is `!crc32c(!0, data)`.
+pub(crate) fn crc32c(mut crc: u32, data: &[u8]) -> u32 {
+    for &byte in data {
+        crc = TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8);
+    }
+
+    crc
+}
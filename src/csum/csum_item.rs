@@ -0,0 +1,101 @@
+use crate::{ChecksumType, Key, KeyType, ObjectId, SuperBlock};
+
+/// A view of an `EXTENT_CSUM` item in the checksum tree.
+///
+/// The key's offset is the logical address of the first checksummed byte. The item contains an
+/// array of checksums, one for each consecutive data sector starting at that address. The size
+/// of each checksum is determined by the filesystem's [`ChecksumType`].
+#[derive(Copy, Clone, Debug)]
+pub struct CsumItem<'a> {
+    start: u64,
+    csum_size: usize,
+    sectorsize: u32,
+    csums: &'a [u8],
+}
+
+impl<'a> CsumItem<'a> {
+    /// Creates a view of the data of an `EXTENT_CSUM` item.
+    ///
+    /// Returns `None` if the key does not belong to a checksum item, the sector size is 0, or the
+    /// data is not a whole number of checksums.
+    pub fn new(
+        key: &Key,
+        bytes: &'a [u8],
+        csum_type: ChecksumType,
+        sectorsize: u32,
+    ) -> Option<Self> {
+        if key.objectid.get() != ObjectId::EXTENT_CSUM.0
+            || key.typed_key_type() != Ok(KeyType::ExtentCsum)
+            || sectorsize == 0
+        {
+            return None;
+        }
+
+        let csum_size = csum_type.digest_size();
+        if !bytes.len().is_multiple_of(csum_size) {
+            return None;
+        }
+
+        Some(Self { start: key.offset.get(), csum_size, sectorsize, csums: bytes })
+    }
+
+    /// Creates a view of the data of an `EXTENT_CSUM` item, using the checksum type and sector
+    /// size of the given superblock.
+    pub fn with_super_block(key: &Key, bytes: &'a [u8], super_block: &SuperBlock) -> Option<Self> {
+        let csum_type = super_block.csum_type;
+        Self::new(key, bytes, csum_type, super_block.sectorsize.get())
+    }
+
+    /// Returns the logical address of the first checksummed byte.
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// Returns the logical address just past the last checksummed byte. Returns `None` if the
+    /// address overflows, which only happens for corrupt items.
+    pub fn end(&self) -> Option<u64> {
+        (self.len() as u64).checked_mul(self.sectorsize as u64)?.checked_add(self.start)
+    }
+
+    /// Returns the number of checksums in the item.
+    pub fn len(&self) -> usize {
+        self.csums.len() / self.csum_size
+    }
+
+    /// Returns whether the item contains no checksums.
+    pub fn is_empty(&self) -> bool {
+        self.csums.is_empty()
+    }
+
+    /// Returns the size of each checksum, in bytes.
+    pub fn csum_size(&self) -> usize {
+        self.csum_size
+    }
+
+    /// Returns the checksum of the data sector containing the given logical address, if it is
+    /// covered by this item. Returns `None` if the end of the item overflows.
+    pub fn csum_for(&self, logical: u64) -> Option<&'a [u8]> {
+        if logical < self.start || logical >= self.end()? {
+            return None;
+        }
+
+        let index = ((logical - self.start) / self.sectorsize as u64) as usize;
+        let offset = index * self.csum_size;
+
+        Some(&self.csums[offset..offset + self.csum_size])
+    }
+
+    /// Returns an iterator over the logical address of each data sector and its checksum.
+    ///
+    /// Iteration stops early if a logical address overflows, which only happens for corrupt
+    /// items.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &'a [u8])> + 'a {
+        let start = self.start;
+        let sectorsize = self.sectorsize as u64;
+
+        self.csums.chunks_exact(self.csum_size).enumerate().map_while(move |(i, csum)| {
+            let logical = (i as u64).checked_mul(sectorsize)?.checked_add(start)?;
+            Some((logical, csum))
+        })
+    }
+}
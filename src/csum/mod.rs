@@ -0,0 +1,3 @@
+mod csum_item;
+
+pub use csum_item::*;
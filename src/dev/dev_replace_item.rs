@@ -0,0 +1,95 @@
+use crate::{Key, KeyType, ObjectId};
+use num_enum::{IntoPrimitive, TryFromPrimitive, TryFromPrimitiveError};
+use static_assertions::const_assert_eq;
+use strum::EnumIter;
+use zerocopy::little_endian::U64 as U64LE;
+use zerocopy_derive::*;
+
+/// Contains the state of a device replace operation. This is stored in the device tree with a key
+/// of ([`ObjectId::DEV_REPLACE`], `DEV_REPLACE`, 0).
+///
+/// While a replace is running or suspended, the target device uses the device ID
+/// [`DEV_REPLACE_DEVID`].
+///
+/// [`DEV_REPLACE_DEVID`]: crate::constants::DEV_REPLACE_DEVID
+#[derive(Copy, Clone, Debug, Hash, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct DevReplaceItem {
+    /// The ID of the device being replaced.
+    pub src_devid: U64LE,
+
+    /// The physical address on the source device up to which data has been copied.
+    pub cursor_left: U64LE,
+
+    /// The physical address on the source device up to which data is being copied.
+    pub cursor_right: U64LE,
+
+    /// Whether data may be read from the source device, which should correspond with a value
+    /// from [`ReplaceReadMode`].
+    pub cont_reading_from_srcdev_mode: U64LE,
+
+    /// The state of the replace operation, which should correspond with a value from
+    /// [`ReplaceState`].
+    pub replace_state: U64LE,
+
+    /// The time the replace operation started, in seconds since the Unix epoch.
+    pub time_started: U64LE,
+
+    /// The time the replace operation stopped, in seconds since the Unix epoch.
+    pub time_stopped: U64LE,
+
+    /// The number of write errors encountered on the target device.
+    pub num_write_errors: U64LE,
+
+    /// The number of read errors that could not be corrected from another copy.
+    pub num_uncorrectable_read_errors: U64LE,
+}
+const_assert_eq!(core::mem::size_of::<DevReplaceItem>(), 72);
+
+impl DevReplaceItem {
+    /// Returns the key of the device replace item.
+    pub fn key() -> Key {
+        Key::new(ObjectId::DEV_REPLACE.0, KeyType::DevReplace, 0)
+    }
+
+    /// Returns the [`replace_state`] as a [`ReplaceState`], if it is a known value.
+    ///
+    /// [`replace_state`]: DevReplaceItem::replace_state
+    pub fn typed_replace_state(&self) -> Result<ReplaceState, TryFromPrimitiveError<ReplaceState>> {
+        ReplaceState::try_from(self.replace_state.get())
+    }
+
+    /// Returns the [`cont_reading_from_srcdev_mode`] as a [`ReplaceReadMode`], if it is a known
+    /// value.
+    ///
+    /// [`cont_reading_from_srcdev_mode`]: DevReplaceItem::cont_reading_from_srcdev_mode
+    pub fn typed_read_mode(
+        &self,
+    ) -> Result<ReplaceReadMode, TryFromPrimitiveError<ReplaceReadMode>> {
+        ReplaceReadMode::try_from(self.cont_reading_from_srcdev_mode.get())
+    }
+}
+
+/// The state of a device replace operation.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, EnumIter, IntoPrimitive, TryFromPrimitive)]
+#[repr(u64)]
+pub enum ReplaceState {
+    NeverStarted = 0,
+    Started = 1,
+    Finished = 2,
+    Canceled = 3,
+
+    /// The replace was interrupted, such as by unmounting, and will resume on the next mount.
+    Suspended = 4,
+}
+
+/// Whether a device replace operation may read data from the device being replaced.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, EnumIter, IntoPrimitive, TryFromPrimitive)]
+#[repr(u64)]
+pub enum ReplaceReadMode {
+    /// Always read from the source device.
+    Always = 0,
+
+    /// Only read from the source device if no other copy is available.
+    Avoid = 1,
+}
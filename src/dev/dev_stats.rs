@@ -0,0 +1,119 @@
+use crate::{DevItem, Key, KeyType, ObjectId};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use static_assertions::const_assert_eq;
+use strum::{EnumCount, EnumIter, IntoEnumIterator};
+use zerocopy::{FromZeros as _, IntoBytes as _, little_endian::U64 as U64LE};
+use zerocopy_derive::*;
+
+/// Contains the persistent error counters of a device. This is used by the `PERSISTENT_ITEM` in
+/// the device tree with a key of ([`ObjectId::DEV_STATS`], `PERSISTENT_ITEM`, devid).
+///
+/// Items written by older implementations may hold fewer counters. See [`read_from_item`] for
+/// reading those.
+///
+/// [`read_from_item`]: DevStatsItem::read_from_item
+#[derive(Copy, Clone, Debug, Hash, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct DevStatsItem {
+    /// The counters, indexed by [`DevStatsCounter`].
+    pub values: [U64LE; DevStatsCounter::COUNT],
+}
+const_assert_eq!(core::mem::size_of::<DevStatsItem>(), 40);
+
+impl DevStatsItem {
+    /// Returns the key of the statistics item for the given device ID.
+    pub fn key(devid: u64) -> Key {
+        Key::new(ObjectId::DEV_STATS.0, KeyType::PersistentItem, devid)
+    }
+
+    /// Returns the device ID that a statistics item key belongs to.
+    ///
+    /// Returns `None` if the key is not a device statistics key.
+    pub fn devid_from_key(key: &Key) -> Option<u64> {
+        if key.objectid.get() != ObjectId::DEV_STATS.0
+            || key.typed_key_type() != Ok(KeyType::PersistentItem)
+        {
+            return None;
+        }
+
+        Some(key.offset.get())
+    }
+
+    /// Reads the counters from the data of an item. Counters missing from a shorter item are
+    /// zero, and any extra data in a longer item is ignored.
+    pub fn read_from_item(bytes: &[u8]) -> Self {
+        let mut item = Self::new_zeroed();
+
+        let len = bytes.len().min(core::mem::size_of::<Self>());
+        item.as_mut_bytes()[..len].copy_from_slice(&bytes[..len]);
+
+        item
+    }
+
+    /// Returns the value of the given counter.
+    pub fn get(&self, counter: DevStatsCounter) -> u64 {
+        let values = self.values;
+        values[counter as usize].get()
+    }
+
+    /// Returns an iterator over every counter and its value.
+    pub fn iter(&self) -> impl Iterator<Item = (DevStatsCounter, u64)> + '_ {
+        DevStatsCounter::iter().map(|counter| (counter, self.get(counter)))
+    }
+}
+
+/// The error counters of a [`DevStatsItem`].
+#[derive(
+    Copy, Clone, Debug, Hash, PartialEq, Eq, EnumIter, EnumCount, IntoPrimitive, TryFromPrimitive,
+)]
+#[repr(u8)]
+pub enum DevStatsCounter {
+    /// The number of failed writes.
+    WriteErrs = 0,
+
+    /// The number of failed reads.
+    ReadErrs = 1,
+
+    /// The number of failed flushes.
+    FlushErrs = 2,
+
+    /// The number of blocks whose checksum did not match.
+    CorruptionErrs = 3,
+
+    /// The number of blocks with an unexpected generation.
+    GenerationErrs = 4,
+}
+
+/// The statistics of a device, together with the device ID from the item key.
+#[derive(Copy, Clone, Debug, Hash)]
+pub struct DevStats {
+    /// The ID of the device these statistics belong to.
+    pub devid: u64,
+
+    /// The error counters of the device.
+    pub stats: DevStatsItem,
+}
+
+impl DevStats {
+    /// Reads the statistics from a device statistics item.
+    ///
+    /// Returns `None` if the key is not a device statistics key.
+    pub fn from_item(key: &Key, bytes: &[u8]) -> Option<Self> {
+        let devid = DevStatsItem::devid_from_key(key)?;
+        Some(Self { devid, stats: DevStatsItem::read_from_item(bytes) })
+    }
+
+    /// Returns whether these statistics belong to the given device.
+    pub fn is_for(&self, device: &DevItem) -> bool {
+        device.devid.get() == self.devid
+    }
+
+    /// Returns the device these statistics belong to from a list of devices, such as the
+    /// `DEV_ITEM` items of the chunk tree.
+    pub fn find_device<'a, I>(&self, devices: I) -> Option<&'a DevItem>
+    where
+        I: IntoIterator<Item = &'a DevItem>,
+    {
+        devices.into_iter().find(|device| self.is_for(device))
+    }
+}
@@ -1,3 +1,7 @@
 mod dev_extent;
+mod dev_replace_item;
+mod dev_stats;
 
 pub use dev_extent::*;
+pub use dev_replace_item::*;
+pub use dev_stats::*;
@@ -0,0 +1,91 @@
+use crate::{Key, KeyType, crc32c::crc32c};
+
+/// A back reference stored as its own item in the extent tree, rather than inline within an
+/// extent item.
+///
+/// Back references are moved out of the extent item when they no longer fit inline. Every keyed
+/// back reference uses the logical address of the extent as the key's object ID. The meaning of
+/// the key's offset depends on the type:
+///
+///  * `TREE_BLOCK_REF`: the object ID of the tree root that allocated the block. No data follows.
+///  * `SHARED_BLOCK_REF`: the logical address of the parent tree block. No data follows.
+///  * `EXTENT_DATA_REF`: a hash of the owner, computed by [`extent_data_ref_hash`]. The item
+///    contains an [`ExtentDataRef`].
+///  * `SHARED_DATA_REF`: the logical address of the leaf containing the file extent item. The
+///    item contains a [`SharedDataRef`].
+///
+/// [`ExtentDataRef`]: crate::ExtentDataRef
+/// [`SharedDataRef`]: crate::SharedDataRef
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum BackrefKey {
+    TreeBlock { bytenr: u64, root: u64 },
+    SharedBlock { bytenr: u64, parent: u64 },
+    ExtentData { bytenr: u64, hash: u64 },
+    SharedData { bytenr: u64, parent: u64 },
+}
+
+impl BackrefKey {
+    /// Creates the key of the `EXTENT_DATA_REF` item for the given extent and owner.
+    ///
+    /// Because the key's offset is a hash, multiple owners may collide on the same key. In that
+    /// case, the item with the next offset is used instead, so the [`ExtentDataRef`] of the found
+    /// item should be compared against the owner.
+    ///
+    /// [`ExtentDataRef`]: crate::ExtentDataRef
+    pub fn extent_data(bytenr: u64, root: u64, objectid: u64, offset: u64) -> Self {
+        BackrefKey::ExtentData { bytenr, hash: extent_data_ref_hash(root, objectid, offset) }
+    }
+
+    /// Interprets a key as a keyed back reference. Returns `None` if the key type is not one of
+    /// the keyed back reference types.
+    pub fn from_key(key: &Key) -> Option<Self> {
+        let bytenr = key.objectid.get();
+        let offset = key.offset.get();
+
+        match key.typed_key_type().ok()? {
+            KeyType::TreeBlockRef => Some(BackrefKey::TreeBlock { bytenr, root: offset }),
+            KeyType::SharedBlockRef => Some(BackrefKey::SharedBlock { bytenr, parent: offset }),
+            KeyType::ExtentDataRef => Some(BackrefKey::ExtentData { bytenr, hash: offset }),
+            KeyType::SharedDataRef => Some(BackrefKey::SharedData { bytenr, parent: offset }),
+            _ => None,
+        }
+    }
+
+    /// Returns the key of the item that holds this back reference.
+    pub fn to_key(&self) -> Key {
+        match *self {
+            BackrefKey::TreeBlock { bytenr, root } => Key::new(bytenr, KeyType::TreeBlockRef, root),
+            BackrefKey::SharedBlock { bytenr, parent } => {
+                Key::new(bytenr, KeyType::SharedBlockRef, parent)
+            }
+            BackrefKey::ExtentData { bytenr, hash } => {
+                Key::new(bytenr, KeyType::ExtentDataRef, hash)
+            }
+            BackrefKey::SharedData { bytenr, parent } => {
+                Key::new(bytenr, KeyType::SharedDataRef, parent)
+            }
+        }
+    }
+
+    /// Returns the logical address of the referenced extent.
+    pub fn bytenr(&self) -> u64 {
+        match *self {
+            BackrefKey::TreeBlock { bytenr, .. }
+            | BackrefKey::SharedBlock { bytenr, .. }
+            | BackrefKey::ExtentData { bytenr, .. }
+            | BackrefKey::SharedData { bytenr, .. } => bytenr,
+        }
+    }
+}
+
+/// Computes the hash used as the key offset of an `EXTENT_DATA_REF` item.
+///
+/// The hash combines the CRC-32C of the root with the CRC-32C of the inode and file offset.
+pub fn extent_data_ref_hash(root: u64, objectid: u64, offset: u64) -> u64 {
+    let high_crc = crc32c(!0, &root.to_le_bytes());
+
+    let low_crc = crc32c(!0, &objectid.to_le_bytes());
+    let low_crc = crc32c(low_crc, &offset.to_le_bytes());
+
+    ((high_crc as u64) << 31) ^ (low_crc as u64)
+}
@@ -1,3 +1,4 @@
+use crate::BlockGroupProfile;
 use bitflags::bitflags;
 use static_assertions::const_assert_eq;
 use zerocopy::little_endian::U64 as U64LE;
@@ -18,6 +19,23 @@ pub struct BlockGroupItem {
 }
 const_assert_eq!(core::mem::size_of::<BlockGroupItem>(), 24);
 
+impl BlockGroupItem {
+    /// Returns the allocation type bits of the [`flags`]. Other bits are discarded.
+    ///
+    /// [`flags`]: BlockGroupItem::flags
+    pub fn allocation_type(&self) -> AllocationType {
+        AllocationType::from_bits_truncate(self.flags.get())
+    }
+
+    /// Returns the replication profile of the [`flags`]. Returns `None` if more than one
+    /// profile is set.
+    ///
+    /// [`flags`]: BlockGroupItem::flags
+    pub fn profile(&self) -> Option<BlockGroupProfile> {
+        BlockGroupProfile::from_flags(self.flags.get())
+    }
+}
+
 bitflags! {
     /// The type of storage a block group allows.
     ///
@@ -29,6 +47,7 @@ bitflags! {
     /// [block group]: BlockGroupItem
     /// [flags]: BlockGroupItem::flags
     /// [SYSTEM]: AllocationType::SYSTEM
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
     pub struct AllocationType: u64 {
         const DATA = 0x1;
         const SYSTEM = 0x2;
@@ -42,6 +61,7 @@ bitflags! {
     /// Only one policy may be set for a given group.
     ///
     /// [block group]: BlockGroupItem
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
     pub struct ReplicationPolicy: u64 {
         const RAID0 = 0x8;
         const RAID1 = 0x10;
@@ -0,0 +1,172 @@
+use crate::{ReplicationPolicy, constants::AVAIL_ALLOC_BIT_SINGLE};
+use strum::EnumIter;
+
+/// The replication profile of a block group or chunk, decoded from the [`ReplicationPolicy`] bits
+/// of its flags.
+///
+/// Unlike [`ReplicationPolicy`], this includes the [`Single`] profile, which is implied when no
+/// policy bit is set.
+///
+/// [`Single`]: BlockGroupProfile::Single
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, EnumIter)]
+pub enum BlockGroupProfile {
+    /// One copy of the data, on one device.
+    Single,
+
+    /// Two copies of the data, on the same device.
+    Dup,
+
+    /// One copy of the data, striped across devices.
+    Raid0,
+
+    /// Two copies of the data, on different devices.
+    Raid1,
+
+    /// Three copies of the data, on different devices.
+    Raid1C3,
+
+    /// Four copies of the data, on different devices.
+    Raid1C4,
+
+    /// Two copies of the data, striped across pairs of devices.
+    Raid10,
+
+    /// Data striped across devices with one parity stripe.
+    Raid5,
+
+    /// Data striped across devices with two parity stripes.
+    Raid6,
+}
+
+impl BlockGroupProfile {
+    /// Decodes the profile from the flags of a block group or chunk. Bits other than the
+    /// [`ReplicationPolicy`] bits and [`AVAIL_ALLOC_BIT_SINGLE`] are ignored.
+    ///
+    /// Returns `None` if more than one profile is set.
+    ///
+    /// [`AVAIL_ALLOC_BIT_SINGLE`]: crate::constants::AVAIL_ALLOC_BIT_SINGLE
+    pub fn from_flags(flags: u64) -> Option<Self> {
+        let policy = ReplicationPolicy::from_bits_truncate(flags);
+        let single = flags & AVAIL_ALLOC_BIT_SINGLE != 0;
+
+        match policy.bits().count_ones() + single as u32 {
+            0 => Some(BlockGroupProfile::Single),
+            1 if single => Some(BlockGroupProfile::Single),
+            1 => Self::from_replication_policy(policy),
+            _ => None,
+        }
+    }
+
+    fn from_replication_policy(policy: ReplicationPolicy) -> Option<Self> {
+        Some(match policy {
+            ReplicationPolicy::DUP => BlockGroupProfile::Dup,
+            ReplicationPolicy::RAID0 => BlockGroupProfile::Raid0,
+            ReplicationPolicy::RAID1 => BlockGroupProfile::Raid1,
+            ReplicationPolicy::RAID1C3 => BlockGroupProfile::Raid1C3,
+            ReplicationPolicy::RAID1C4 => BlockGroupProfile::Raid1C4,
+            ReplicationPolicy::RAID10 => BlockGroupProfile::Raid10,
+            ReplicationPolicy::RAID5 => BlockGroupProfile::Raid5,
+            ReplicationPolicy::RAID6 => BlockGroupProfile::Raid6,
+            _ => return None,
+        })
+    }
+
+    /// Returns the [`ReplicationPolicy`] bit of the profile. This is empty for
+    /// [`Single`](BlockGroupProfile::Single).
+    pub fn replication_policy(self) -> ReplicationPolicy {
+        match self {
+            BlockGroupProfile::Single => ReplicationPolicy::empty(),
+            BlockGroupProfile::Dup => ReplicationPolicy::DUP,
+            BlockGroupProfile::Raid0 => ReplicationPolicy::RAID0,
+            BlockGroupProfile::Raid1 => ReplicationPolicy::RAID1,
+            BlockGroupProfile::Raid1C3 => ReplicationPolicy::RAID1C3,
+            BlockGroupProfile::Raid1C4 => ReplicationPolicy::RAID1C4,
+            BlockGroupProfile::Raid10 => ReplicationPolicy::RAID10,
+            BlockGroupProfile::Raid5 => ReplicationPolicy::RAID5,
+            BlockGroupProfile::Raid6 => ReplicationPolicy::RAID6,
+        }
+    }
+
+    /// Returns the number of complete copies of the data that are stored.
+    pub fn ncopies(self) -> u8 {
+        match self {
+            BlockGroupProfile::Single
+            | BlockGroupProfile::Raid0
+            | BlockGroupProfile::Raid5
+            | BlockGroupProfile::Raid6 => 1,
+            BlockGroupProfile::Dup | BlockGroupProfile::Raid1 | BlockGroupProfile::Raid10 => 2,
+            BlockGroupProfile::Raid1C3 => 3,
+            BlockGroupProfile::Raid1C4 => 4,
+        }
+    }
+
+    /// Returns the number of parity stripes in each full stripe.
+    pub fn nparity(self) -> u8 {
+        match self {
+            BlockGroupProfile::Raid5 => 1,
+            BlockGroupProfile::Raid6 => 2,
+            _ => 0,
+        }
+    }
+
+    /// Returns the minimum number of devices the profile can be allocated on.
+    pub fn devs_min(self) -> u8 {
+        match self {
+            BlockGroupProfile::Single | BlockGroupProfile::Dup | BlockGroupProfile::Raid0 => 1,
+            BlockGroupProfile::Raid1 | BlockGroupProfile::Raid10 | BlockGroupProfile::Raid5 => 2,
+            BlockGroupProfile::Raid1C3 | BlockGroupProfile::Raid6 => 3,
+            BlockGroupProfile::Raid1C4 => 4,
+        }
+    }
+
+    /// Returns the maximum number of devices a single chunk of the profile spans. Returns `None`
+    /// if the profile stripes across as many devices as are available.
+    pub fn devs_max(self) -> Option<u8> {
+        match self {
+            BlockGroupProfile::Single | BlockGroupProfile::Dup => Some(1),
+            BlockGroupProfile::Raid1 => Some(2),
+            BlockGroupProfile::Raid1C3 => Some(3),
+            BlockGroupProfile::Raid1C4 => Some(4),
+            BlockGroupProfile::Raid0
+            | BlockGroupProfile::Raid10
+            | BlockGroupProfile::Raid5
+            | BlockGroupProfile::Raid6 => None,
+        }
+    }
+
+    /// Returns the number of devices that can fail without losing data.
+    pub fn tolerated_failures(self) -> u8 {
+        match self {
+            BlockGroupProfile::Single | BlockGroupProfile::Dup | BlockGroupProfile::Raid0 => 0,
+            BlockGroupProfile::Raid1 | BlockGroupProfile::Raid10 | BlockGroupProfile::Raid5 => 1,
+            BlockGroupProfile::Raid1C3 | BlockGroupProfile::Raid6 => 2,
+            BlockGroupProfile::Raid1C4 => 3,
+        }
+    }
+
+    /// Returns the number of stripes of a chunk with this profile that hold distinct data, given
+    /// its total number of stripes. Parity stripes and extra copies are not counted.
+    pub fn data_stripes(self, num_stripes: u16) -> u16 {
+        match self {
+            BlockGroupProfile::Raid0 => num_stripes,
+            BlockGroupProfile::Raid10 => num_stripes / 2,
+            BlockGroupProfile::Raid5 | BlockGroupProfile::Raid6 => {
+                num_stripes.saturating_sub(self.nparity() as u16)
+            }
+            _ => 1,
+        }
+    }
+
+    /// Returns the fraction of the raw space of a chunk with this profile that holds usable data,
+    /// given its total number of stripes. For example, this is 0.5 for RAID-1 and 0.75 for RAID-5
+    /// on 4 devices.
+    ///
+    /// Returns 0 if `num_stripes` is 0.
+    pub fn usable_ratio(self, num_stripes: u16) -> f64 {
+        if num_stripes == 0 {
+            return 0.0;
+        }
+
+        self.data_stripes(num_stripes) as f64 / num_stripes as f64
+    }
+}
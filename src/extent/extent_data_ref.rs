@@ -1,3 +1,4 @@
+use crate::extent_data_ref_hash;
 use static_assertions::const_assert_eq;
 use zerocopy::little_endian::{U32 as U32LE, U64 as U64LE};
 use zerocopy_derive::*;
@@ -23,3 +24,11 @@ pub struct ExtentDataRef {
     pub count: U32LE,
 }
 const_assert_eq!(core::mem::size_of::<ExtentDataRef>(), 28);
+
+impl ExtentDataRef {
+    /// Computes the hash of this reference's owner, as used in the key offset of an
+    /// `EXTENT_DATA_REF` item.
+    pub fn hash(&self) -> u64 {
+        extent_data_ref_hash(self.root.get(), self.objectid.get(), self.offset.get())
+    }
+}
@@ -2,7 +2,7 @@ use crate::{ExtentDataRef, SharedDataRef};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use static_assertions::{const_assert, const_assert_eq};
 use strum::EnumIter;
-use zerocopy::little_endian::U64 as U64LE;
+use zerocopy::{TryFromBytes as _, little_endian::U64 as U64LE};
 use zerocopy_derive::*;
 
 /// This acts as a header for different types of inline extent back references inside extent or
@@ -20,12 +20,12 @@ pub struct ExtentInlineRefHeader {
     /// This field also determines the semantic importance of [`offset`].
     ///
     /// [`ExtentInlineRefType`]: crate::ExtentInlineRefType
-    /// [`offset`]: ExtentInlineRef::offset
+    /// [`offset`]: ExtentInlineRefHeader::offset
     pub ref_type: ExtentInlineRefType,
 
     /// This field has different functions depending on the value of [`ref_type`].
     ///
-    /// [`ref_type`]: ExtentInlineRef::ref_type
+    /// [`ref_type`]: Self::ref_type
     pub offset: U64LE,
 }
 const_assert_eq!(core::mem::size_of::<ExtentInlineRefHeader>(), 9);
@@ -43,7 +43,7 @@ pub struct ExtentInlineRefFull {
 
     /// This field has different functions depending on the value of [`ref_type`].
     ///
-    /// [`ref_type`]: ExtentInlineRef::ref_type
+    /// [`ref_type`]: Self::ref_type
     pub tail: ExtentInlineRefTail,
 }
 const_assert_eq!(core::mem::size_of::<ExtentInlineRefFull>(), 29);
@@ -54,9 +54,9 @@ impl ExtentInlineRefFull {
     /// [`ref_type`]: Self::ref_type
     pub fn offset(&self) -> Option<u64> {
         match self.ref_type {
-            ExtentInlineRefType::TreeBlockRef | ExtentInlineRefType::SharedBlockRef => {
-                Some(unsafe { self.tail.offset.get() })
-            }
+            ExtentInlineRefType::TreeBlockRef
+            | ExtentInlineRefType::SharedBlockRef
+            | ExtentInlineRefType::ExtentOwnerRef => Some(unsafe { self.tail.offset.get() }),
             ExtentInlineRefType::SharedDataRef => {
                 Some(unsafe { self.tail.shared_data_tail.offset.get() })
             }
@@ -101,6 +101,21 @@ impl ExtentInlineRefFull {
         }
     }
 
+    pub fn as_owner_ref(&self) -> Option<&ExtentInlineOwnerRef> {
+        const_assert!(
+            core::mem::size_of::<ExtentInlineRefFull>()
+                >= core::mem::size_of::<ExtentInlineOwnerRef>()
+        );
+
+        if self.ref_type == ExtentInlineRefType::ExtentOwnerRef {
+            // Safety: We know that the bytes are valid for `ExtentInlineOwnerRef` because
+            // `ref_type` is `ExtentInlineRefType::ExtentOwnerRef`.
+            Some(unsafe { &*(self as *const ExtentInlineRefFull as *const ExtentInlineOwnerRef) })
+        } else {
+            None
+        }
+    }
+
     pub fn as_shared_block_ref(&self) -> Option<&ExtentInlineSharedBlockRef> {
         const_assert!(
             core::mem::size_of::<ExtentInlineRefFull>()
@@ -153,6 +168,111 @@ impl ExtentInlineRefFull {
     }
 }
 
+/// A typed inline extent back reference, as yielded by [`ExtentInlineRefIter`].
+#[derive(Copy, Clone, Debug)]
+pub enum ExtentInlineRef<'a> {
+    TreeBlock(&'a ExtentInlineTreeBlockRef),
+    SharedBlock(&'a ExtentInlineSharedBlockRef),
+    ExtentData(&'a ExtentInlineExtentDataRef),
+    SharedData(&'a ExtentInlineSharedDataRef),
+    Owner(&'a ExtentInlineOwnerRef),
+}
+
+impl ExtentInlineRef<'_> {
+    /// Returns the type of the reference.
+    pub fn ref_type(&self) -> ExtentInlineRefType {
+        match self {
+            ExtentInlineRef::TreeBlock(_) => ExtentInlineRefType::TreeBlockRef,
+            ExtentInlineRef::SharedBlock(_) => ExtentInlineRefType::SharedBlockRef,
+            ExtentInlineRef::ExtentData(_) => ExtentInlineRefType::ExtentDataRef,
+            ExtentInlineRef::SharedData(_) => ExtentInlineRefType::SharedDataRef,
+            ExtentInlineRef::Owner(_) => ExtentInlineRefType::ExtentOwnerRef,
+        }
+    }
+}
+
+impl ExtentInlineRefType {
+    /// Returns the size of an inline reference of this type, including the type byte.
+    pub fn inline_size(&self) -> usize {
+        match self {
+            ExtentInlineRefType::TreeBlockRef => core::mem::size_of::<ExtentInlineTreeBlockRef>(),
+            ExtentInlineRefType::SharedBlockRef => {
+                core::mem::size_of::<ExtentInlineSharedBlockRef>()
+            }
+            ExtentInlineRefType::ExtentDataRef => core::mem::size_of::<ExtentInlineExtentDataRef>(),
+            ExtentInlineRefType::SharedDataRef => core::mem::size_of::<ExtentInlineSharedDataRef>(),
+            ExtentInlineRefType::ExtentOwnerRef => core::mem::size_of::<ExtentInlineOwnerRef>(),
+        }
+    }
+}
+
+/// An iterator over the inline back references that follow an [`ExtentItem`] and, if present,
+/// its [`TreeBlockInfo`].
+///
+/// Iteration stops early if a reference has an unknown type or the remaining bytes are too short
+/// to hold it. Those bytes can be retrieved with [`remainder`].
+///
+/// [`ExtentItem`]: crate::ExtentItem
+/// [`TreeBlockInfo`]: crate::TreeBlockInfo
+/// [`remainder`]: ExtentInlineRefIter::remainder
+#[derive(Clone, Debug)]
+pub struct ExtentInlineRefIter<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ExtentInlineRefIter<'a> {
+    /// Creates an iterator over the inline references in the given bytes, which should start
+    /// immediately after the [`ExtentItem`] or [`TreeBlockInfo`].
+    ///
+    /// [`ExtentItem`]: crate::ExtentItem
+    /// [`TreeBlockInfo`]: crate::TreeBlockInfo
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the bytes that have not been parsed yet. This is empty once iteration has
+    /// completed over well-formed data.
+    pub fn remainder(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> Iterator for ExtentInlineRefIter<'a> {
+    type Item = ExtentInlineRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ref_type = ExtentInlineRefType::try_from(*self.bytes.first()?).ok()?;
+
+        let (inline_ref, rest) = match ref_type {
+            ExtentInlineRefType::TreeBlockRef => {
+                let (r, rest) = ExtentInlineTreeBlockRef::try_ref_from_prefix(self.bytes).ok()?;
+                (ExtentInlineRef::TreeBlock(r), rest)
+            }
+            ExtentInlineRefType::SharedBlockRef => {
+                let (r, rest) = ExtentInlineSharedBlockRef::try_ref_from_prefix(self.bytes).ok()?;
+                (ExtentInlineRef::SharedBlock(r), rest)
+            }
+            ExtentInlineRefType::ExtentDataRef => {
+                let (r, rest) = ExtentInlineExtentDataRef::try_ref_from_prefix(self.bytes).ok()?;
+                (ExtentInlineRef::ExtentData(r), rest)
+            }
+            ExtentInlineRefType::SharedDataRef => {
+                let (r, rest) = ExtentInlineSharedDataRef::try_ref_from_prefix(self.bytes).ok()?;
+                (ExtentInlineRef::SharedData(r), rest)
+            }
+            ExtentInlineRefType::ExtentOwnerRef => {
+                let (r, rest) = ExtentInlineOwnerRef::try_ref_from_prefix(self.bytes).ok()?;
+                (ExtentInlineRef::Owner(r), rest)
+            }
+        };
+        self.bytes = rest;
+
+        Some(inline_ref)
+    }
+}
+
+impl core::iter::FusedIterator for ExtentInlineRefIter<'_> {}
+
 /// Union that contains the actual data for the inline extent reference.
 #[derive(Copy, Clone, FromBytes, Unaligned, KnownLayout, Immutable)]
 #[repr(C, packed)]
@@ -264,6 +384,29 @@ const_assert_eq!(
     core::mem::size_of::<ExtentInlineRefType>()
 );
 
+/// An [`ExtentInlineRefHeader`] and/or [`ExtentInlineRefFull`] where the value is known to be
+/// [`ExtentInlineRefType::ExtentOwnerRef`].
+#[derive(Copy, Clone, Debug, Hash, TryFromBytes, IntoBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct ExtentInlineOwnerRef {
+    /// This is here to allow [`TryFromBytes`] to enforce the type of the reference.
+    ref_type: ExtentInlineOwnerRefType,
+
+    /// The object ID of the tree root that owns the extent.
+    pub offset: U64LE,
+}
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, TryFromBytes, IntoBytes, KnownLayout, Immutable)]
+#[repr(u8)]
+enum ExtentInlineOwnerRefType {
+    #[allow(dead_code)]
+    ExtentOwnerRef = ExtentInlineRefType::ExtentOwnerRef as u8,
+}
+const_assert_eq!(
+    core::mem::size_of::<ExtentInlineOwnerRefType>(),
+    core::mem::size_of::<ExtentInlineRefType>()
+);
+
 /// The type of [`ExtentInlineRefHeader`] or [`ExtentInlineRefFull`].
 ///
 /// [`ExtentInlineRefHeader`]: crate::ExtentInlineRefHeader
@@ -304,7 +447,7 @@ pub enum ExtentInlineRefType {
     /// An [`ExtentDataRef`] is located immediately after the [`type`] field and overlaps the unused
     /// [`offset`] field.
     ///
-    /// [`type`]: ExtentInlineRef::type
+    /// [`type`]: ExtentInlineRefHeader::ref_type
     /// [`offset`]: ExtentInlineRefHeader::offset
     ExtentDataRef = 178,
 
@@ -318,4 +461,12 @@ pub enum ExtentInlineRefType {
     ///
     /// [`offset`]: ExtentInlineRefHeader::offset
     SharedDataRef = 184,
+
+    /// The reference records the owner of the extent for simple quotas. It always comes before
+    /// any other inline reference.
+    ///
+    /// [`offset`] contains the object ID of the tree root that owns the extent.
+    ///
+    /// [`offset`]: ExtentInlineRefHeader::offset
+    ExtentOwnerRef = 172,
 }
@@ -0,0 +1,120 @@
+use crate::{ExtentInlineRefIter, Key, KeyType};
+use bitflags::bitflags;
+use static_assertions::const_assert_eq;
+use zerocopy::{FromBytes as _, little_endian::U64 as U64LE};
+use zerocopy_derive::*;
+
+/// Contains the reference count and properties of an allocated extent. This is the fixed part of
+/// `EXTENT_ITEM` and `METADATA_ITEM` items in the extent tree.
+///
+/// For tree blocks in an `EXTENT_ITEM`, a [`TreeBlockInfo`] follows. Afterwards, zero or more
+/// inline back references follow until the end of the item. To parse all of these at once, see
+/// [`ExtentItemView::parse`].
+#[derive(Copy, Clone, Debug, Hash, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct ExtentItem {
+    /// The number of references held to this extent.
+    pub refs: U64LE,
+
+    /// The transaction ID of the transaction that allocated this extent.
+    pub generation: U64LE,
+
+    /// Flags for the extent. See [`ExtentFlags`] for values.
+    pub flags: U64LE,
+}
+const_assert_eq!(core::mem::size_of::<ExtentItem>(), 24);
+
+impl ExtentItem {
+    /// Returns the [`flags`] as [`ExtentFlags`]. Unknown bits are retained.
+    ///
+    /// [`flags`]: ExtentItem::flags
+    pub fn typed_flags(&self) -> ExtentFlags {
+        ExtentFlags::from_bits_retain(self.flags.get())
+    }
+}
+
+bitflags! {
+    /// The flags of an [`ExtentItem`].
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct ExtentFlags: u64 {
+        /// The extent contains file data.
+        const DATA = 0x1;
+
+        /// The extent is a tree block.
+        const TREE_BLOCK = 0x2;
+
+        /// All references to this tree block are shared back references.
+        const FULL_BACKREF = 0x100;
+    }
+}
+
+/// Describes the first key and level of a tree block. This follows an [`ExtentItem`] in an
+/// `EXTENT_ITEM` when the extent is a tree block.
+///
+/// This is not present in `METADATA_ITEM` items, where the level is stored in the key's offset
+/// instead.
+#[derive(Copy, Clone, Debug, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct TreeBlockInfo {
+    /// The first key in the tree block.
+    pub key: Key,
+
+    /// The level of the tree block.
+    pub level: u8,
+}
+const_assert_eq!(core::mem::size_of::<TreeBlockInfo>(), 18);
+
+/// A parsed `EXTENT_ITEM` or `METADATA_ITEM` item.
+#[derive(Clone, Debug)]
+pub struct ExtentItemView<'a> {
+    /// The fixed part of the item.
+    pub item: &'a ExtentItem,
+
+    /// The tree block information. This is only present for tree blocks in an `EXTENT_ITEM`.
+    pub tree_block_info: Option<&'a TreeBlockInfo>,
+
+    /// The level of the tree block, taken from either [`tree_block_info`] or the key of a
+    /// `METADATA_ITEM`. This is `None` for data extents.
+    ///
+    /// [`tree_block_info`]: ExtentItemView::tree_block_info
+    pub level: Option<u8>,
+
+    /// The inline back references that follow.
+    pub inline_refs: ExtentInlineRefIter<'a>,
+}
+
+impl<'a> ExtentItemView<'a> {
+    /// Parses the data of an item, using its key to determine the layout.
+    ///
+    /// Returns `None` if the key is neither an `EXTENT_ITEM` nor a `METADATA_ITEM`, or if the
+    /// data is too short.
+    pub fn parse(key: &Key, bytes: &'a [u8]) -> Option<Self> {
+        let (item, rest) = ExtentItem::ref_from_prefix(bytes).ok()?;
+
+        match key.typed_key_type().ok()? {
+            KeyType::ExtentItem if item.typed_flags().contains(ExtentFlags::TREE_BLOCK) => {
+                let (info, rest) = TreeBlockInfo::ref_from_prefix(rest).ok()?;
+
+                Some(Self {
+                    item,
+                    tree_block_info: Some(info),
+                    level: Some(info.level),
+                    inline_refs: ExtentInlineRefIter::new(rest),
+                })
+            }
+            KeyType::ExtentItem => Some(Self {
+                item,
+                tree_block_info: None,
+                level: None,
+                inline_refs: ExtentInlineRefIter::new(rest),
+            }),
+            KeyType::MetadataItem => Some(Self {
+                item,
+                tree_block_info: None,
+                level: Some(key.offset.get() as u8),
+                inline_refs: ExtentInlineRefIter::new(rest),
+            }),
+            _ => None,
+        }
+    }
+}
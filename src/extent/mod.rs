@@ -1,9 +1,15 @@
+mod backref_key;
 mod block_group_item;
+mod block_group_profile;
 mod extent_data_ref;
 mod extent_inline_ref;
+mod extent_item;
 mod shared_data_ref;
 
+pub use backref_key::*;
 pub use block_group_item::*;
+pub use block_group_profile::*;
 pub use extent_data_ref::*;
 pub use extent_inline_ref::*;
+pub use extent_item::*;
 pub use shared_data_ref::*;
@@ -0,0 +1,96 @@
+use crate::{FreeSpaceRange, Key, KeyType};
+
+/// An iterator over the free ranges of a free space bitmap.
+///
+/// Each bit of the bitmap represents one sector, starting from the least significant bit of the
+/// first byte. A set bit marks the sector as free. Consecutive free sectors are merged into a
+/// single [`FreeSpaceRange`].
+///
+/// Iteration stops early if the address or length of a range overflows, which only happens for
+/// corrupt items.
+#[derive(Clone, Debug)]
+pub struct FreeSpaceBitmapIter<'a> {
+    start: u64,
+    sectorsize: u64,
+    bitmap: &'a [u8],
+    num_bits: usize,
+    pos: usize,
+}
+
+impl<'a> FreeSpaceBitmapIter<'a> {
+    /// Creates an iterator over every bit of the bitmap, where the first bit represents the
+    /// sector at the logical address `start`.
+    pub fn new(start: u64, sectorsize: u32, bitmap: &'a [u8]) -> Self {
+        Self { start, sectorsize: sectorsize as u64, bitmap, num_bits: bitmap.len() * 8, pos: 0 }
+    }
+
+    /// Creates an iterator over the data of a `FREE_SPACE_BITMAP` item, where the key's object ID
+    /// and offset are the start and length of the range covered by the bitmap.
+    ///
+    /// Returns `None` if the key is not a `FREE_SPACE_BITMAP` key, the sector size is 0, or the
+    /// bitmap is too short to cover the range.
+    pub fn from_item(key: &Key, bitmap: &'a [u8], sectorsize: u32) -> Option<Self> {
+        if key.typed_key_type() != Ok(KeyType::FreeSpaceBitmap) || sectorsize == 0 {
+            return None;
+        }
+
+        let num_bits = usize::try_from(key.offset.get() / sectorsize as u64).ok()?;
+        if bitmap.len() < num_bits.div_ceil(8) {
+            return None;
+        }
+
+        Some(Self { num_bits, ..Self::new(key.objectid.get(), sectorsize, bitmap) })
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        self.bitmap[index / 8] & (1 << (index % 8)) != 0
+    }
+
+    /// Returns the index of the first bit at or after `index` with the given value, or the
+    /// number of bits if there is none.
+    fn find_bit(&self, mut index: usize, value: bool) -> usize {
+        let skip = if value { 0x00 } else { 0xFF };
+
+        while index < self.num_bits {
+            if index.is_multiple_of(8) && self.bitmap[index / 8] == skip {
+                index += 8;
+            } else if self.bit(index) == value {
+                return index;
+            } else {
+                index += 1;
+            }
+        }
+
+        self.num_bits
+    }
+}
+
+impl Iterator for FreeSpaceBitmapIter<'_> {
+    type Item = FreeSpaceRange;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.find_bit(self.pos, true);
+        if first >= self.num_bits {
+            self.pos = self.num_bits;
+            return None;
+        }
+
+        let end = self.find_bit(first, false);
+        self.pos = end;
+
+        let range = (first as u64).checked_mul(self.sectorsize).and_then(|offset| {
+            Some(FreeSpaceRange {
+                start: self.start.checked_add(offset)?,
+                length: ((end - first) as u64).checked_mul(self.sectorsize)?,
+            })
+        });
+
+        if range.is_none() {
+            self.pos = self.num_bits;
+        }
+
+        range
+    }
+}
+
+impl core::iter::FusedIterator for FreeSpaceBitmapIter<'_> {}
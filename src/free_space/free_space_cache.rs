@@ -0,0 +1,423 @@
+use crate::{FreeSpaceBitmapIter, FreeSpaceRange, Key, ObjectId, crc32c::crc32c};
+use num_enum::{IntoPrimitive, TryFromPrimitive, TryFromPrimitiveError};
+use static_assertions::const_assert_eq;
+use strum::EnumIter;
+use zerocopy::{FromBytes as _, little_endian::U64 as U64LE};
+use zerocopy_derive::*;
+
+/// Locates the free space cache file of a block group, as used by the original free space cache
+/// (`space_cache=v1`).
+///
+/// This is stored in the root tree with a key of ([`ObjectId::FREE_SPACE`], 0, block group
+/// start). The cache file itself is a hidden inode in the root tree, whose
+/// [`InodeItem::block_group`] points back at the block group. See [`FreeSpaceCache`] for parsing
+/// the contents of the file.
+///
+/// [`InodeItem::block_group`]: crate::InodeItem::block_group
+#[derive(Copy, Clone, Debug, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct FreeSpaceHeader {
+    /// The key of the inode item of the cache file.
+    pub location: Key,
+
+    /// The transaction ID of the transaction that wrote the cache.
+    pub generation: U64LE,
+
+    /// The number of entries in the cache, including bitmap entries.
+    pub num_entries: U64LE,
+
+    /// The number of bitmap entries in the cache.
+    pub num_bitmaps: U64LE,
+}
+const_assert_eq!(core::mem::size_of::<FreeSpaceHeader>(), 41);
+
+impl FreeSpaceHeader {
+    /// Returns the key of the free space header for the block group starting at the given logical
+    /// address.
+    pub fn key(block_group_start: u64) -> Key {
+        Key {
+            objectid: U64LE::new(ObjectId::FREE_SPACE.0),
+            key_type: 0,
+            offset: U64LE::new(block_group_start),
+        }
+    }
+
+    /// Returns the logical address of the block group that a free space header key belongs to.
+    ///
+    /// Returns `None` if the key is not a free space header key.
+    pub fn block_group_from_key(key: &Key) -> Option<u64> {
+        if key.objectid.get() != ObjectId::FREE_SPACE.0 || key.key_type != 0 {
+            return None;
+        }
+
+        Some(key.offset.get())
+    }
+}
+
+/// An entry in a free space cache file. This describes either a free extent or a bitmap of free
+/// sectors.
+#[derive(Copy, Clone, Debug, Hash, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct FreeSpaceEntry {
+    /// The logical address of the start of the extent or bitmap.
+    pub offset: U64LE,
+
+    /// For extents, the length of the extent. For bitmaps, the number of free bytes in the
+    /// bitmap.
+    pub bytes: U64LE,
+
+    /// The type of the entry, which should correspond with a value from [`FreeSpaceEntryType`].
+    pub entry_type: u8,
+}
+const_assert_eq!(core::mem::size_of::<FreeSpaceEntry>(), 17);
+
+impl FreeSpaceEntry {
+    /// Returns the [`entry_type`] as a [`FreeSpaceEntryType`], if it is a known value.
+    ///
+    /// [`entry_type`]: FreeSpaceEntry::entry_type
+    pub fn typed_entry_type(
+        &self,
+    ) -> Result<FreeSpaceEntryType, TryFromPrimitiveError<FreeSpaceEntryType>> {
+        FreeSpaceEntryType::try_from(self.entry_type)
+    }
+}
+
+/// The type of a [`FreeSpaceEntry`].
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    EnumIter,
+    IntoPrimitive,
+    TryFromPrimitive,
+    IntoBytes,
+    TryFromBytes,
+    Unaligned,
+    KnownLayout,
+    Immutable,
+)]
+#[repr(u8)]
+pub enum FreeSpaceEntryType {
+    /// The entry describes a free extent.
+    Extent = 1,
+
+    /// The entry describes a bitmap, which is stored in its own page after all of the entries.
+    Bitmap = 2,
+}
+const_assert_eq!(core::mem::size_of::<FreeSpaceEntryType>(), 1);
+
+/// The reason a free space cache file could not be used.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum FreeSpaceCacheError {
+    /// The file is empty, is not a whole number of pages, or its pages are too small to hold the
+    /// checksums and generation.
+    InvalidSize,
+
+    /// The file is too short to hold all of the entries and bitmaps in the header.
+    Truncated,
+
+    /// The checksum of the given page does not match the stored checksum.
+    ChecksumMismatch { page: usize },
+
+    /// The generation stored in the file does not match the header, so the cache is stale.
+    GenerationMismatch { expected: u64, found: u64 },
+
+    /// The entry at the given index has an unknown type.
+    InvalidEntryType { index: usize, entry_type: u8 },
+
+    /// The number of bitmap entries does not match the header.
+    BitmapCountMismatch { expected: u64, found: u64 },
+}
+
+impl core::fmt::Display for FreeSpaceCacheError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FreeSpaceCacheError::InvalidSize => write!(f, "invalid free space cache size"),
+            FreeSpaceCacheError::Truncated => write!(f, "free space cache is truncated"),
+            FreeSpaceCacheError::ChecksumMismatch { page } => {
+                write!(f, "free space cache checksum mismatch on page {page}")
+            }
+            FreeSpaceCacheError::GenerationMismatch { expected, found } => write!(
+                f,
+                "free space cache generation {found} does not match header generation {expected}"
+            ),
+            FreeSpaceCacheError::InvalidEntryType { index, entry_type } => {
+                write!(f, "free space cache entry {index} has invalid type {entry_type}")
+            }
+            FreeSpaceCacheError::BitmapCountMismatch { expected, found } => write!(
+                f,
+                "free space cache has {found} bitmap entries but header expects {expected}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for FreeSpaceCacheError {}
+
+/// A validated free space cache file, as used by the original free space cache
+/// (`space_cache=v1`).
+///
+/// The file is divided into pages. The first page starts with a CRC-32C checksum for every page,
+/// followed by the generation of the cache. The [`FreeSpaceEntry`] structures follow, never
+/// crossing a page boundary. Each bitmap then occupies a whole page, starting at the page after
+/// the last entry, in the same order as the bitmap entries.
+#[derive(Copy, Clone, Debug)]
+pub struct FreeSpaceCache<'a> {
+    data: &'a [u8],
+    page_size: usize,
+    sectorsize: u32,
+    generation: u64,
+    num_entries: usize,
+    num_bitmaps: usize,
+    first_bitmap_page: usize,
+}
+
+impl<'a> FreeSpaceCache<'a> {
+    /// Parses and validates the contents of a free space cache file.
+    ///
+    /// `page_size` is the page size of the system that wrote the cache, which is typically 4096.
+    /// `sectorsize` is the sector size of the filesystem, which is the unit of each bitmap bit.
+    ///
+    /// The checksums of all pages holding entries or bitmaps are verified, as is the generation
+    /// stored in the file. The cache is also stale if the generation of the cache inode does not
+    /// match [`FreeSpaceHeader::generation`], or if [`SuperBlock::cache_generation`] does not
+    /// match the generation of the superblock. Those checks are left to the caller.
+    ///
+    /// [`SuperBlock::cache_generation`]: crate::SuperBlock::cache_generation
+    pub fn parse(
+        header: &FreeSpaceHeader,
+        data: &'a [u8],
+        page_size: usize,
+        sectorsize: u32,
+    ) -> Result<Self, FreeSpaceCacheError> {
+        if page_size == 0 || data.is_empty() || !data.len().is_multiple_of(page_size) {
+            return Err(FreeSpaceCacheError::InvalidSize);
+        }
+
+        let num_pages = data.len() / page_size;
+        let crcs_len = num_pages * core::mem::size_of::<u32>();
+        if crcs_len + core::mem::size_of::<u64>() > page_size {
+            return Err(FreeSpaceCacheError::InvalidSize);
+        }
+
+        let num_entries = usize::try_from(header.num_entries.get())
+            .map_err(|_| FreeSpaceCacheError::Truncated)?;
+        let num_bitmaps = usize::try_from(header.num_bitmaps.get())
+            .map_err(|_| FreeSpaceCacheError::Truncated)?;
+
+        let mut cache = Self {
+            data,
+            page_size,
+            sectorsize,
+            generation: 0,
+            num_entries,
+            num_bitmaps,
+            first_bitmap_page: 0,
+        };
+
+        // Find the last page holding entries and make sure every entry is present.
+        let mut last_entry_page = 0;
+        let mut offset = crcs_len + core::mem::size_of::<u64>();
+        for _ in 0..num_entries {
+            offset = cache.next_entry_offset(offset);
+            if offset + core::mem::size_of::<FreeSpaceEntry>() > data.len() {
+                return Err(FreeSpaceCacheError::Truncated);
+            }
+
+            last_entry_page = offset / page_size;
+            offset += core::mem::size_of::<FreeSpaceEntry>();
+        }
+
+        cache.first_bitmap_page = last_entry_page + 1;
+        if num_bitmaps > num_pages.saturating_sub(cache.first_bitmap_page) {
+            return Err(FreeSpaceCacheError::Truncated);
+        }
+
+        for page in (0..=last_entry_page)
+            .chain(cache.first_bitmap_page..cache.first_bitmap_page + num_bitmaps)
+        {
+            if cache.stored_crc(page) != cache.computed_crc(page) {
+                return Err(FreeSpaceCacheError::ChecksumMismatch { page });
+            }
+        }
+
+        let generation_bytes = &data[crcs_len..crcs_len + core::mem::size_of::<u64>()];
+        cache.generation = u64::from_le_bytes(generation_bytes.try_into().unwrap());
+        if cache.generation != header.generation.get() {
+            return Err(FreeSpaceCacheError::GenerationMismatch {
+                expected: header.generation.get(),
+                found: cache.generation,
+            });
+        }
+
+        let mut found_bitmaps = 0;
+        for (index, entry) in cache.entries().enumerate() {
+            match entry.typed_entry_type() {
+                Ok(FreeSpaceEntryType::Extent) => {}
+                Ok(FreeSpaceEntryType::Bitmap) => found_bitmaps += 1,
+                Err(_) => {
+                    return Err(FreeSpaceCacheError::InvalidEntryType {
+                        index,
+                        entry_type: entry.entry_type,
+                    });
+                }
+            }
+        }
+
+        if found_bitmaps != num_bitmaps {
+            return Err(FreeSpaceCacheError::BitmapCountMismatch {
+                expected: num_bitmaps as u64,
+                found: found_bitmaps as u64,
+            });
+        }
+
+        Ok(cache)
+    }
+
+    /// Returns the generation stored in the cache file.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns an iterator over the entries in the cache.
+    pub fn entries(&self) -> FreeSpaceEntryIter<'a> {
+        FreeSpaceEntryIter {
+            cache: *self,
+            offset: self.num_pages() * core::mem::size_of::<u32>() + core::mem::size_of::<u64>(),
+            remaining: self.num_entries,
+        }
+    }
+
+    /// Returns the bitmap page of the bitmap entry with the given index, counting only bitmap
+    /// entries.
+    pub fn bitmap(&self, index: usize) -> Option<&'a [u8]> {
+        if index >= self.num_bitmaps {
+            return None;
+        }
+
+        let start = (self.first_bitmap_page + index) * self.page_size;
+        Some(&self.data[start..start + self.page_size])
+    }
+
+    /// Returns an iterator over the free ranges in the cache, from both extent and bitmap
+    /// entries.
+    pub fn ranges(&self) -> FreeSpaceCacheRangeIter<'a> {
+        FreeSpaceCacheRangeIter {
+            cache: *self,
+            entries: self.entries(),
+            bitmap_index: 0,
+            bitmap: None,
+        }
+    }
+
+    fn num_pages(&self) -> usize {
+        self.data.len() / self.page_size
+    }
+
+    /// Returns the offset of the entry at or after `offset`, skipping to the next page if the
+    /// current page does not have room for it.
+    fn next_entry_offset(&self, offset: usize) -> usize {
+        let page_remaining = self.page_size - offset % self.page_size;
+        if page_remaining < core::mem::size_of::<FreeSpaceEntry>() {
+            offset + page_remaining
+        } else {
+            offset
+        }
+    }
+
+    fn stored_crc(&self, page: usize) -> u32 {
+        let offset = page * core::mem::size_of::<u32>();
+        u32::from_le_bytes(self.data[offset..offset + 4].try_into().unwrap())
+    }
+
+    fn computed_crc(&self, page: usize) -> u32 {
+        // The checksums themselves are excluded from the first page's checksum.
+        let start = if page == 0 { self.num_pages() * core::mem::size_of::<u32>() } else { 0 };
+        let bytes = &self.data[page * self.page_size..(page + 1) * self.page_size];
+
+        !crc32c(!0, &bytes[start..])
+    }
+}
+
+/// An iterator over the entries of a [`FreeSpaceCache`].
+#[derive(Clone, Debug)]
+pub struct FreeSpaceEntryIter<'a> {
+    cache: FreeSpaceCache<'a>,
+    offset: usize,
+    remaining: usize,
+}
+
+impl<'a> Iterator for FreeSpaceEntryIter<'a> {
+    type Item = &'a FreeSpaceEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let offset = self.cache.next_entry_offset(self.offset);
+        let (entry, _) = FreeSpaceEntry::ref_from_prefix(&self.cache.data[offset..]).ok()?;
+
+        self.offset = offset + core::mem::size_of::<FreeSpaceEntry>();
+        self.remaining -= 1;
+
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for FreeSpaceEntryIter<'_> {}
+
+impl core::iter::FusedIterator for FreeSpaceEntryIter<'_> {}
+
+/// An iterator over the free ranges of a [`FreeSpaceCache`].
+///
+/// Ranges are yielded in the order of the entries. Ranges from adjacent entries are not merged.
+#[derive(Clone, Debug)]
+pub struct FreeSpaceCacheRangeIter<'a> {
+    cache: FreeSpaceCache<'a>,
+    entries: FreeSpaceEntryIter<'a>,
+    bitmap_index: usize,
+    bitmap: Option<FreeSpaceBitmapIter<'a>>,
+}
+
+impl Iterator for FreeSpaceCacheRangeIter<'_> {
+    type Item = FreeSpaceRange;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(range) = self.bitmap.as_mut().and_then(|bitmap| bitmap.next()) {
+                return Some(range);
+            }
+            self.bitmap = None;
+
+            let entry = self.entries.next()?;
+            match entry.typed_entry_type() {
+                Ok(FreeSpaceEntryType::Extent) => {
+                    return Some(FreeSpaceRange {
+                        start: entry.offset.get(),
+                        length: entry.bytes.get(),
+                    });
+                }
+                Ok(FreeSpaceEntryType::Bitmap) => {
+                    let bitmap = self.cache.bitmap(self.bitmap_index)?;
+                    self.bitmap_index += 1;
+                    self.bitmap = Some(FreeSpaceBitmapIter::new(
+                        entry.offset.get(),
+                        self.cache.sectorsize,
+                        bitmap,
+                    ));
+                }
+                Err(_) => return None,
+            }
+        }
+    }
+}
+
+impl core::iter::FusedIterator for FreeSpaceCacheRangeIter<'_> {}
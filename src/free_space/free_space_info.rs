@@ -0,0 +1,73 @@
+use crate::{Key, KeyType};
+use bitflags::bitflags;
+use static_assertions::const_assert_eq;
+use zerocopy::little_endian::U32 as U32LE;
+use zerocopy_derive::*;
+
+/// Describes how the free space of a block group is tracked in the free space tree. This is used
+/// by `FREE_SPACE_INFO` items, where the key's object ID and offset are the logical address and
+/// length of the block group.
+///
+/// The free space of the block group is stored in the items that follow, either as
+/// `FREE_SPACE_EXTENT` items or as `FREE_SPACE_BITMAP` items depending on [`flags`].
+///
+/// [`flags`]: FreeSpaceInfo::flags
+#[derive(Copy, Clone, Debug, Hash, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct FreeSpaceInfo {
+    /// The number of free extents in the block group.
+    pub extent_count: U32LE,
+
+    /// Flags for the block group's free space. See [`FreeSpaceInfoFlags`] for values.
+    pub flags: U32LE,
+}
+const_assert_eq!(core::mem::size_of::<FreeSpaceInfo>(), 8);
+
+impl FreeSpaceInfo {
+    /// Returns the [`flags`] as [`FreeSpaceInfoFlags`]. Unknown bits are retained.
+    ///
+    /// [`flags`]: FreeSpaceInfo::flags
+    pub fn typed_flags(&self) -> FreeSpaceInfoFlags {
+        FreeSpaceInfoFlags::from_bits_retain(self.flags.get())
+    }
+}
+
+bitflags! {
+    /// The flags of a [`FreeSpaceInfo`].
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct FreeSpaceInfoFlags: u32 {
+        /// The free space is stored as `FREE_SPACE_BITMAP` items instead of `FREE_SPACE_EXTENT`
+        /// items.
+        const USING_BITMAPS = 0x1;
+    }
+}
+
+/// A range of free space, in logical bytes.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct FreeSpaceRange {
+    /// The logical address of the start of the range.
+    pub start: u64,
+
+    /// The length of the range, in bytes.
+    pub length: u64,
+}
+
+impl FreeSpaceRange {
+    /// Returns the range described by a `FREE_SPACE_EXTENT` key, where the object ID and offset
+    /// are the start and length of a free extent. These items have no data.
+    ///
+    /// Returns `None` if the key is not a `FREE_SPACE_EXTENT` key.
+    pub fn from_extent_key(key: &Key) -> Option<Self> {
+        if key.typed_key_type() != Ok(KeyType::FreeSpaceExtent) {
+            return None;
+        }
+
+        Some(Self { start: key.objectid.get(), length: key.offset.get() })
+    }
+
+    /// Returns the logical address just past the end of the range. Returns `None` if the address
+    /// overflows, which only happens for corrupt items.
+    pub fn end(&self) -> Option<u64> {
+        self.start.checked_add(self.length)
+    }
+}
@@ -0,0 +1,7 @@
+mod free_space_bitmap;
+mod free_space_cache;
+mod free_space_info;
+
+pub use free_space_bitmap::*;
+pub use free_space_cache::*;
+pub use free_space_info::*;
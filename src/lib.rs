@@ -3,15 +3,26 @@
 pub mod aliases;
 pub mod constants;
 
+mod checksum;
 mod chunk;
 mod core;
+mod crc32c;
+mod csum;
 mod dev;
 mod extent;
+mod free_space;
+mod qgroup;
 mod types;
+mod uuid;
 
 pub use crate::aliases::*;
+pub use crate::checksum::*;
 pub use crate::chunk::*;
 pub use crate::core::*;
+pub use crate::csum::*;
 pub use crate::dev::*;
 pub use crate::extent::*;
+pub use crate::free_space::*;
+pub use crate::qgroup::*;
 pub use crate::types::*;
+pub use crate::uuid::*;
@@ -0,0 +1,9 @@
+mod qgroup_id;
+mod qgroup_info_item;
+mod qgroup_limit_item;
+mod qgroup_status_item;
+
+pub use qgroup_id::*;
+pub use qgroup_info_item::*;
+pub use qgroup_limit_item::*;
+pub use qgroup_status_item::*;
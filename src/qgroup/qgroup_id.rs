@@ -0,0 +1,93 @@
+use crate::{Key, KeyType};
+
+/// The ID of a quota group.
+///
+/// The upper 16 bits hold the level of the group and the lower 48 bits hold its ID within that
+/// level. Level 0 groups are created for every subvolume, where the ID is the subvolume ID.
+/// Groups at higher levels can contain groups from lower levels.
+///
+/// The [`Display`] implementation formats the ID as `level/id`, such as `0/256` or `1/100`.
+///
+/// [`Display`]: core::fmt::Display
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct QgroupId(pub u64);
+
+impl QgroupId {
+    const LEVEL_SHIFT: u32 = 48;
+
+    /// Creates a quota group ID from its level and ID within that level. Bits of `subvolid`
+    /// beyond the lower 48 are discarded.
+    pub fn new(level: u16, subvolid: u64) -> Self {
+        Self(((level as u64) << Self::LEVEL_SHIFT) | (subvolid & ((1 << Self::LEVEL_SHIFT) - 1)))
+    }
+
+    /// Returns the level of the quota group.
+    pub fn level(self) -> u16 {
+        (self.0 >> Self::LEVEL_SHIFT) as u16
+    }
+
+    /// Returns the ID of the quota group within its level. For level 0 groups, this is the
+    /// subvolume ID.
+    pub fn subvolid(self) -> u64 {
+        self.0 & ((1 << Self::LEVEL_SHIFT) - 1)
+    }
+}
+
+impl From<u64> for QgroupId {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<QgroupId> for u64 {
+    fn from(value: QgroupId) -> Self {
+        value.0
+    }
+}
+
+impl core::fmt::Display for QgroupId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}/{}", self.level(), self.subvolid())
+    }
+}
+
+/// A relation between two quota groups, as described by the key of a `QGROUP_RELATION` item.
+/// These items have no data.
+///
+/// Every relation is stored twice: once with the member group as the [`src`] and the parent group
+/// as the [`dst`], and once the other way around.
+///
+/// [`src`]: QgroupRelation::src
+/// [`dst`]: QgroupRelation::dst
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct QgroupRelation {
+    pub src: QgroupId,
+    pub dst: QgroupId,
+}
+
+impl QgroupRelation {
+    /// Interprets a key as a quota group relation. Returns `None` if the key is not a
+    /// `QGROUP_RELATION` key.
+    pub fn from_key(key: &Key) -> Option<Self> {
+        if key.typed_key_type() != Ok(KeyType::QgroupRelation) {
+            return None;
+        }
+
+        Some(Self { src: QgroupId(key.objectid.get()), dst: QgroupId(key.offset.get()) })
+    }
+
+    /// Returns the key of the item that holds this relation.
+    pub fn to_key(&self) -> Key {
+        Key::new(self.src.0, KeyType::QgroupRelation, self.dst.0)
+    }
+
+    /// Returns the member group of the relation, which is the group at the lower level.
+    pub fn member(&self) -> QgroupId {
+        self.src.min(self.dst)
+    }
+
+    /// Returns the parent group of the relation, which is the group at the higher level.
+    pub fn parent(&self) -> QgroupId {
+        self.src.max(self.dst)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{Key, KeyType, QgroupId};
+use static_assertions::const_assert_eq;
+use zerocopy::little_endian::U64 as U64LE;
+use zerocopy_derive::*;
+
+/// Contains the space usage of a quota group. This is used by `QGROUP_INFO` items, with a key of
+/// (0, `QGROUP_INFO`, qgroup ID).
+#[derive(Copy, Clone, Debug, Hash, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct QgroupInfoItem {
+    /// The transaction ID of the transaction that last updated this item.
+    pub generation: U64LE,
+
+    /// The number of bytes referenced by the group.
+    pub rfer: U64LE,
+
+    /// The number of bytes referenced by the group, after compression.
+    pub rfer_cmpr: U64LE,
+
+    /// The number of bytes referenced exclusively by the group.
+    pub excl: U64LE,
+
+    /// The number of bytes referenced exclusively by the group, after compression.
+    pub excl_cmpr: U64LE,
+}
+const_assert_eq!(core::mem::size_of::<QgroupInfoItem>(), 40);
+
+impl QgroupInfoItem {
+    /// Returns the key of the info item for the given quota group.
+    pub fn key(qgroupid: QgroupId) -> Key {
+        Key::new(0, KeyType::QgroupInfo, qgroupid.0)
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{Key, KeyType, QgroupId};
+use bitflags::bitflags;
+use static_assertions::const_assert_eq;
+use zerocopy::little_endian::U64 as U64LE;
+use zerocopy_derive::*;
+
+/// Contains the limits of a quota group. This is used by `QGROUP_LIMIT` items, with a key of
+/// (0, `QGROUP_LIMIT`, qgroup ID).
+///
+/// Each limit only applies if the matching flag is set in [`flags`].
+///
+/// [`flags`]: QgroupLimitItem::flags
+#[derive(Copy, Clone, Debug, Hash, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct QgroupLimitItem {
+    /// Flags indicating which limits are set. See [`QgroupLimitFlags`] for values.
+    pub flags: U64LE,
+
+    /// The maximum number of bytes the group may reference.
+    pub max_rfer: U64LE,
+
+    /// The maximum number of bytes the group may reference exclusively.
+    pub max_excl: U64LE,
+
+    /// The number of referenced bytes reserved for the group.
+    pub rsv_rfer: U64LE,
+
+    /// The number of exclusively referenced bytes reserved for the group.
+    pub rsv_excl: U64LE,
+}
+const_assert_eq!(core::mem::size_of::<QgroupLimitItem>(), 40);
+
+impl QgroupLimitItem {
+    /// Returns the key of the limit item for the given quota group.
+    pub fn key(qgroupid: QgroupId) -> Key {
+        Key::new(0, KeyType::QgroupLimit, qgroupid.0)
+    }
+
+    /// Returns the [`flags`] as [`QgroupLimitFlags`]. Unknown bits are retained.
+    ///
+    /// [`flags`]: QgroupLimitItem::flags
+    pub fn typed_flags(&self) -> QgroupLimitFlags {
+        QgroupLimitFlags::from_bits_retain(self.flags.get())
+    }
+
+    /// Returns [`max_rfer`] if the limit is set.
+    ///
+    /// [`max_rfer`]: QgroupLimitItem::max_rfer
+    pub fn max_referenced(&self) -> Option<u64> {
+        self.typed_flags().contains(QgroupLimitFlags::MAX_RFER).then(|| self.max_rfer.get())
+    }
+
+    /// Returns [`max_excl`] if the limit is set.
+    ///
+    /// [`max_excl`]: QgroupLimitItem::max_excl
+    pub fn max_exclusive(&self) -> Option<u64> {
+        self.typed_flags().contains(QgroupLimitFlags::MAX_EXCL).then(|| self.max_excl.get())
+    }
+}
+
+bitflags! {
+    /// The flags of a [`QgroupLimitItem`].
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct QgroupLimitFlags: u64 {
+        /// [`QgroupLimitItem::max_rfer`] is set.
+        const MAX_RFER = 0x1;
+
+        /// [`QgroupLimitItem::max_excl`] is set.
+        const MAX_EXCL = 0x2;
+
+        /// [`QgroupLimitItem::rsv_rfer`] is set.
+        const RSV_RFER = 0x4;
+
+        /// [`QgroupLimitItem::rsv_excl`] is set.
+        const RSV_EXCL = 0x8;
+
+        /// Referenced limits apply to compressed sizes.
+        const RFER_CMPR = 0x10;
+
+        /// Exclusive limits apply to compressed sizes.
+        const EXCL_CMPR = 0x20;
+    }
+}
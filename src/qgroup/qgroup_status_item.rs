@@ -0,0 +1,60 @@
+use crate::{Key, KeyType};
+use bitflags::bitflags;
+use static_assertions::const_assert_eq;
+use zerocopy::little_endian::U64 as U64LE;
+use zerocopy_derive::*;
+
+/// Contains the global state of quota groups. There is a single `QGROUP_STATUS` item in the quota
+/// tree, with a key of (0, `QGROUP_STATUS`, 0).
+///
+/// When simple quotas are enabled, the item is followed by the transaction ID of the transaction
+/// that enabled them.
+#[derive(Copy, Clone, Debug, Hash, IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct QgroupStatusItem {
+    /// The version of the quota tree format. This is currently 1.
+    pub version: U64LE,
+
+    /// The transaction ID of the transaction that last updated the quota groups.
+    pub generation: U64LE,
+
+    /// Flags for the quota groups. See [`QgroupStatusFlags`] for values.
+    pub flags: U64LE,
+
+    /// The logical address up to which a running rescan has progressed.
+    pub rescan: U64LE,
+}
+const_assert_eq!(core::mem::size_of::<QgroupStatusItem>(), 32);
+
+impl QgroupStatusItem {
+    /// Returns the key of the quota status item.
+    pub fn key() -> Key {
+        Key::new(0, KeyType::QgroupStatus, 0)
+    }
+
+    /// Returns the [`flags`] as [`QgroupStatusFlags`]. Unknown bits are retained.
+    ///
+    /// [`flags`]: QgroupStatusItem::flags
+    pub fn typed_flags(&self) -> QgroupStatusFlags {
+        QgroupStatusFlags::from_bits_retain(self.flags.get())
+    }
+}
+
+bitflags! {
+    /// The flags of a [`QgroupStatusItem`].
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct QgroupStatusFlags: u64 {
+        /// Quotas are enabled.
+        const ON = 0x1;
+
+        /// A rescan is in progress.
+        const RESCAN = 0x2;
+
+        /// The quota group numbers are out of date and require a rescan.
+        const INCONSISTENT = 0x4;
+
+        /// Simple quotas are in use, which only account extents to the subvolume that created
+        /// them.
+        const SIMPLE_MODE = 0x8;
+    }
+}
@@ -4,3 +4,127 @@ use crate::constants::UUID_SIZE;
 
 /// An inline array of bytes representing a UUID within the filesystem.
 pub type UuidBytes = [u8; UUID_SIZE];
+
+/// A UUID, wrapping [`UuidBytes`] for formatting, parsing and comparison.
+///
+/// The [`Display`] implementation formats the UUID in the canonical lowercase hyphenated form,
+/// such as `6ba7b810-9dad-11d1-80b4-00c04fd430c8`, and [`FromStr`] parses it back, ignoring case.
+///
+/// [`Display`]: core::fmt::Display
+/// [`FromStr`]: core::str::FromStr
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Uuid(pub UuidBytes);
+
+impl Uuid {
+    /// The nil UUID, where every byte is zero. This is used to mark unset UUID fields.
+    pub const NIL: Self = Self([0; UUID_SIZE]);
+
+    /// The byte offsets after which a hyphen is placed in the hyphenated form.
+    const HYPHENS_AFTER: [usize; 4] = [4, 6, 8, 10];
+
+    /// The length of the hyphenated form.
+    const HYPHENATED_LEN: usize = UUID_SIZE * 2 + Self::HYPHENS_AFTER.len();
+
+    /// Returns whether this is the nil UUID.
+    pub fn is_nil(&self) -> bool {
+        *self == Self::NIL
+    }
+
+    /// Returns the bytes of the UUID.
+    pub fn as_bytes(&self) -> &UuidBytes {
+        &self.0
+    }
+}
+
+impl From<UuidBytes> for Uuid {
+    fn from(value: UuidBytes) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Uuid> for UuidBytes {
+    fn from(value: Uuid) -> Self {
+        value.0
+    }
+}
+
+impl core::fmt::Display for Uuid {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, byte) in self.0.iter().enumerate() {
+            if Self::HYPHENS_AFTER.contains(&i) {
+                f.write_str("-")?;
+            }
+
+            write!(f, "{byte:02x}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::str::FromStr for Uuid {
+    type Err = UuidParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.as_bytes();
+        if s.len() != Self::HYPHENATED_LEN {
+            return Err(UuidParseError::InvalidLength { len: s.len() });
+        }
+
+        let mut uuid = Self::NIL;
+        let mut index = 0;
+
+        for (i, byte) in uuid.0.iter_mut().enumerate() {
+            if Self::HYPHENS_AFTER.contains(&i) {
+                if s[index] != b'-' {
+                    return Err(UuidParseError::InvalidCharacter { index });
+                }
+
+                index += 1;
+            }
+
+            let high = hex_value(s[index]).ok_or(UuidParseError::InvalidCharacter { index })?;
+            let low = hex_value(s[index + 1])
+                .ok_or(UuidParseError::InvalidCharacter { index: index + 1 })?;
+
+            *byte = (high << 4) | low;
+            index += 2;
+        }
+
+        Ok(uuid)
+    }
+}
+
+fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// An error returned when parsing a [`Uuid`] from a string fails.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum UuidParseError {
+    /// The string is not 36 bytes long.
+    InvalidLength { len: usize },
+
+    /// The byte at the given index is not a hexadecimal digit or a hyphen in the expected place.
+    InvalidCharacter { index: usize },
+}
+
+impl core::fmt::Display for UuidParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            UuidParseError::InvalidLength { len } => {
+                write!(f, "invalid UUID length {len}, expected {}", Uuid::HYPHENATED_LEN)
+            }
+            UuidParseError::InvalidCharacter { index } => {
+                write!(f, "invalid character in UUID at index {index}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for UuidParseError {}
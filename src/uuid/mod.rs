@@ -0,0 +1,3 @@
+mod uuid_item;
+
+pub use uuid_item::*;
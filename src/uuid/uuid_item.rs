@@ -0,0 +1,90 @@
+use crate::{Key, KeyType, UuidBytes};
+use zerocopy::{FromBytes as _, little_endian::U64 as U64LE};
+use zerocopy_derive::*;
+
+/// The key of an item in the UUID tree, which maps the UUID of a subvolume to its ID.
+///
+/// The UUID is split in half to form the key: the first 8 bytes are read as a little-endian
+/// integer for the object ID, and the last 8 bytes for the offset. The data of the item is a
+/// [`UuidItem`].
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum UuidKey {
+    /// A `UUID_KEY_SUBVOL` key, which maps the [`uuid`] of a subvolume.
+    ///
+    /// [`uuid`]: crate::RootItem::uuid
+    Subvol(UuidBytes),
+
+    /// A `UUID_KEY_RECEIVED_SUBVOL` key, which maps the [`received_uuid`] of a received
+    /// subvolume.
+    ///
+    /// [`received_uuid`]: crate::RootItem::received_uuid
+    ReceivedSubvol(UuidBytes),
+}
+
+impl UuidKey {
+    /// Interprets a key as a UUID tree key. Returns `None` if the key type is not one of the UUID
+    /// key types.
+    pub fn from_key(key: &Key) -> Option<Self> {
+        let mut uuid = UuidBytes::default();
+        uuid[..8].copy_from_slice(&key.objectid.get().to_le_bytes());
+        uuid[8..].copy_from_slice(&key.offset.get().to_le_bytes());
+
+        match key.typed_key_type().ok()? {
+            KeyType::UuidKeySubvol => Some(UuidKey::Subvol(uuid)),
+            KeyType::UuidKeyReceivedSubvol => Some(UuidKey::ReceivedSubvol(uuid)),
+            _ => None,
+        }
+    }
+
+    /// Returns the key of the item that holds the subvolume IDs for this UUID.
+    pub fn to_key(&self) -> Key {
+        let (key_type, uuid) = match self {
+            UuidKey::Subvol(uuid) => (KeyType::UuidKeySubvol, uuid),
+            UuidKey::ReceivedSubvol(uuid) => (KeyType::UuidKeyReceivedSubvol, uuid),
+        };
+
+        let (high, low) = uuid.split_at(8);
+        Key::new(
+            u64::from_le_bytes(high.try_into().unwrap()),
+            key_type,
+            u64::from_le_bytes(low.try_into().unwrap()),
+        )
+    }
+
+    /// Returns the UUID that this key maps.
+    pub fn uuid(&self) -> &UuidBytes {
+        match self {
+            UuidKey::Subvol(uuid) | UuidKey::ReceivedSubvol(uuid) => uuid,
+        }
+    }
+}
+
+/// The data of an item in the UUID tree, which lists the IDs of the subvolumes with the UUID in
+/// the item's [`UuidKey`].
+///
+/// A subvolume UUID usually maps to a single subvolume, but a received UUID is shared by every
+/// subvolume received from the same source.
+#[derive(IntoBytes, FromBytes, Unaligned, KnownLayout, Immutable)]
+#[repr(C, packed)]
+pub struct UuidItem {
+    /// The IDs of the subvolumes.
+    pub subvol_ids: [U64LE],
+}
+
+impl UuidItem {
+    /// Interprets the data of a UUID tree item. Returns `None` if the length of the data is not a
+    /// multiple of 8 bytes.
+    pub fn parse(bytes: &[u8]) -> Option<&Self> {
+        Self::ref_from_bytes(bytes).ok()
+    }
+
+    /// Returns an iterator over the subvolume IDs.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = u64> + '_ {
+        self.subvol_ids.iter().map(|id| id.get())
+    }
+
+    /// Returns whether the given subvolume ID is listed.
+    pub fn contains(&self, subvol_id: u64) -> bool {
+        self.iter().any(|id| id == subvol_id)
+    }
+}